@@ -0,0 +1,246 @@
+use {
+    std::{
+        any::{Any, TypeId},
+        sync::Arc,
+    },
+    crate::{
+        buf::Buffer,
+        Track,
+        TrackInterpolator,
+        Key,
+        Data,
+        Node,
+    }
+};
+
+/// Monoid over the node data used by `Track::aggregate`
+pub trait TrackAggregate {
+    type Data;
+    type Aggregate: Clone;
+
+    fn identity(&self) -> Self::Aggregate;
+
+    fn lift(&self, data: &Self::Data) -> Self::Aggregate;
+
+    /// Joins the aggregates of the adjacent nodes, the `lhs` ones are before the `rhs` ones
+    fn combine(&self, lhs: &Self::Aggregate, rhs: &Self::Aggregate) -> Self::Aggregate;
+}
+
+/// Type erased aggregate index stored in a track
+pub(crate) trait AggregateIndex<Data>: Send + Sync {
+    fn capacity(&self) -> usize;
+
+    fn reset(&mut self, capacity: usize);
+
+    fn set(&mut self, slot: usize, data: &Data);
+
+    fn clone_index(&self) -> Box<dyn AggregateIndex<Data>>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Aggregate index shared by a track with its snapshots until the track changes it
+pub(crate) type SharedAggregateIndex<Data> = Arc<dyn AggregateIndex<Data>>;
+
+/// Segment tree over the buffer slots.
+/// A node with the absolute index `i` is kept in the slot `i % capacity`,
+/// so the slots of the removed nodes are simply overwritten by the next pushes.
+pub(crate) struct AggregateTree<A: TrackAggregate> {
+    aggregator: Arc<A>,
+    nodes: Vec<A::Aggregate>,
+}
+
+impl<A: TrackAggregate> AggregateTree<A> {
+    fn new(aggregator: A) -> Self {
+        Self {
+            aggregator: Arc::new(aggregator),
+            nodes: vec![]
+        }
+    }
+
+    fn capacity(&self) -> usize {
+        self.nodes.len() / 2
+    }
+
+    /// Aggregate of the nodes with the absolute indices from the `first` to the `last` inclusive
+    pub(crate) fn query_nodes(&self, first: usize, last: usize) -> A::Aggregate {
+        let capacity = self.capacity();
+        let begin = first % capacity;
+        let end = last % capacity;
+
+        if begin <= end {
+            self.query(begin, end + 1)
+        } else {
+            self.aggregator.combine(
+                &self.query(begin, capacity),
+                &self.query(0, end + 1)
+            )
+        }
+    }
+
+    /// Aggregate of the slots from the `begin` to the `end`, not including the `end`
+    fn query(&self, mut begin: usize, mut end: usize) -> A::Aggregate {
+        let capacity = self.capacity();
+        let mut lhs = self.aggregator.identity();
+        let mut rhs = self.aggregator.identity();
+
+        begin += capacity;
+        end += capacity;
+
+        while begin < end {
+            if begin % 2 == 1 {
+                lhs = self.aggregator.combine(&lhs, &self.nodes[begin]);
+                begin += 1;
+            }
+
+            if end % 2 == 1 {
+                end -= 1;
+                rhs = self.aggregator.combine(&self.nodes[end], &rhs);
+            }
+
+            begin /= 2;
+            end /= 2;
+        }
+
+        self.aggregator.combine(&lhs, &rhs)
+    }
+}
+
+impl<A> AggregateIndex<A::Data> for AggregateTree<A>
+where
+    A: TrackAggregate + Send + Sync + 'static,
+    A::Aggregate: Send + Sync + 'static
+{
+    fn capacity(&self) -> usize {
+        AggregateTree::capacity(self)
+    }
+
+    fn reset(&mut self, capacity: usize) {
+        self.nodes = vec![self.aggregator.identity(); 2 * capacity];
+    }
+
+    fn set(&mut self, slot: usize, data: &A::Data) {
+        let mut index = slot + AggregateTree::capacity(self);
+        self.nodes[index] = self.aggregator.lift(data);
+
+        while index > 1 {
+            index /= 2;
+            self.nodes[index] = self.aggregator.combine(
+                &self.nodes[2 * index],
+                &self.nodes[2 * index + 1]
+            );
+        }
+    }
+
+    fn clone_index(&self) -> Box<dyn AggregateIndex<A::Data>> {
+        Box::new(AggregateTree {
+            aggregator: self.aggregator.clone(),
+            nodes: self.nodes.clone()
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Starts maintaining the index of the `aggregator`, replacing the previous one of the same type.
+    /// Indices of the different aggregator types are maintained together.
+    pub fn enable_aggregate<A>(&mut self, aggregator: A)
+    where
+        A: TrackAggregate<Data = Data<I>> + Send + Sync + 'static,
+        A::Aggregate: Send + Sync + 'static
+    {
+        let mut tree = AggregateTree::new(aggregator);
+        Self::reindex(&self.buf, self.buf_index_base, &mut tree);
+        self.aggregates.insert(TypeId::of::<A>(), Arc::new(tree));
+    }
+
+    /// Returns `false` if the index of the `A` is not enabled
+    pub fn disable_aggregate<A>(&mut self) -> bool
+    where
+        A: TrackAggregate<Data = Data<I>> + 'static
+    {
+        self.aggregates.remove(&TypeId::of::<A>()).is_some()
+    }
+
+    /// Aggregate of the nodes which keys are in the [`from`, `to`].
+    /// Returns `None` if there are no such nodes or the index of the `A` is not enabled.
+    pub fn aggregate<A>(&self, from: &Key<I>, to: &Key<I>) -> Option<A::Aggregate>
+    where
+        A: TrackAggregate<Data = Data<I>> + 'static
+    {
+        let tree = self.aggregate_tree::<A>()?;
+
+        if from > to {
+            return None;
+        }
+
+        let first = match self.cursor_at(from) {
+            Some(cursor) if self.cursor_key(cursor) < *from => self.cursor_next(cursor)?,
+            Some(cursor) => cursor,
+            None => self.cursor_first()?
+        };
+        let last = self.cursor_at(to)?;
+
+        if first.index > last.index {
+            return None;
+        }
+
+        Some(tree.query_nodes(first.index, last.index))
+    }
+
+    pub(crate) fn aggregate_tree<A>(&self) -> Option<&AggregateTree<A>>
+    where
+        A: TrackAggregate<Data = Data<I>> + 'static
+    {
+        self.aggregates.get(&TypeId::of::<A>())?
+            .as_any()
+            .downcast_ref::<AggregateTree<A>>()
+    }
+
+    /// Updates the aggregate index after the last node is pushed
+    pub(crate) fn index_node_end(&mut self) {
+        self.index_node(self.buf_index_base + self.buf.len() - 1);
+    }
+
+    /// Updates the aggregate index after the node with the absolute `index` is changed
+    pub(crate) fn index_node(&mut self, index: usize) {
+        let capacity = self.buf.capacity();
+
+        for aggregates in self.aggregates.values_mut() {
+            let aggregates = Self::unshare(aggregates);
+
+            if aggregates.capacity() == capacity {
+                aggregates.set(index % capacity, &self.buf[index - self.buf_index_base]);
+            } else {
+                Self::reindex(&self.buf, self.buf_index_base, aggregates);
+            }
+        }
+    }
+
+    pub(crate) fn reindex_aggregates(&mut self) {
+        for aggregates in self.aggregates.values_mut() {
+            Self::reindex(&self.buf, self.buf_index_base, Self::unshare(aggregates));
+        }
+    }
+
+    /// Copies the index if it is shared with a snapshot
+    fn unshare(aggregates: &mut SharedAggregateIndex<Data<I>>) -> &mut dyn AggregateIndex<Data<I>> {
+        if Arc::get_mut(aggregates).is_none() {
+            *aggregates = Arc::from(aggregates.clone_index());
+        }
+
+        Arc::get_mut(aggregates).unwrap()
+    }
+
+    fn reindex(buf: &Buffer<Node<I>>, buf_index_base: usize, aggregates: &mut dyn AggregateIndex<Data<I>>) {
+        let capacity = buf.capacity();
+        aggregates.reset(capacity);
+
+        for (offset, node) in buf.iter().enumerate() {
+            aggregates.set((buf_index_base + offset) % capacity, node);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    ScalableDistance,
+    InterpolationResult,
+    Key,
+    KeyDistance,
+    Output,
+};
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator,
+    KeyDistance<I>: ScalableDistance
+{
+    /// Key of the minimal `metric` between this track and the `other` one, and the metric value there.
+    /// The segments between the merged node keys of both tracks are refined
+    /// until the keys around the minimum are not farther than the `tolerance`.
+    /// The metric is expected to have a single minimum inside each segment.
+    /// Returns `None` if the tracks do not overlap or one of them has a single node, which can't be interpolated.
+    pub fn closest_approach<J, M>(
+        &mut self,
+        other: &mut Track<J>,
+        tolerance: &KeyDistance<I>,
+        mut metric: M
+    ) -> InterpolationResult<Option<(Key<I>, f64)>, I::Error>
+    where
+        J: TrackInterpolator<Key = Key<I>, Error = I::Error>,
+        M: FnMut(&Output<I>, &J::Output) -> f64
+    {
+        if self.is_empty() || other.is_empty() {
+            return Ok(None);
+        }
+
+        // A single node track has the same start and end
+        if self.key_start == self.key_end || other.key_start == other.key_end {
+            return Ok(None);
+        }
+
+        let begin = std::cmp::max(&self.key_start, &other.key_start).clone();
+        let end = std::cmp::min(&self.key_end, &other.key_end).clone();
+        if begin > end {
+            return Ok(None);
+        }
+
+        let mut keys = self.nodes()
+            .map(|(key, _)| key)
+            .chain(other.nodes().map(|(key, _)| key))
+            .filter(|key| begin < *key && *key < end)
+            .collect::<Vec<_>>();
+
+        keys.push(begin);
+        keys.push(end);
+        keys.sort();
+        keys.dedup();
+
+        let mut distance = |key: &Key<I>| -> InterpolationResult<f64, I::Error> {
+            let lhs = self.interpolate_closed(key)?;
+            let rhs = other.interpolate_closed(key)?;
+
+            Ok(metric(&lhs, &rhs))
+        };
+
+        let mut closest = (keys[0].clone(), distance(&keys[0])?);
+        let mut update = |key: &Key<I>, value: f64| {
+            if value < closest.1 {
+                closest = (key.clone(), value);
+            }
+        };
+
+        for segment in keys.windows(2) {
+            let (mut lhs, mut rhs) = (segment[0].clone(), segment[1].clone());
+            update(&rhs, distance(&rhs)?);
+
+            while lhs.distance(&rhs) > *tolerance {
+                let third = lhs.distance(&rhs).scale_f64(1.0 / 3.0);
+                let lhs_third = lhs.add_distance(&third);
+                let rhs_third = rhs.add_distance(&-third);
+                if lhs_third == lhs || lhs_third > rhs_third {
+                    break;
+                }
+
+                let lhs_value = distance(&lhs_third)?;
+                let rhs_value = distance(&rhs_third)?;
+                update(&lhs_third, lhs_value);
+                update(&rhs_third, rhs_value);
+
+                if lhs_value <= rhs_value {
+                    rhs = rhs_third;
+                } else {
+                    lhs = lhs_third;
+                }
+            }
+        }
+
+        Ok(Some(closest))
+    }
+}
@@ -1,4 +1,7 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    ops::{Index, IndexMut},
+    sync::Arc,
+};
 
 pub type Result<T> = std::result::Result<(), Error<T>>;
 
@@ -7,9 +10,78 @@ pub enum Error<T> {
     Overflow(T)
 }
 
-/// Track internal buffer (ring buffer)
+const CHUNK_SIZE: usize = 32;
+
+/// Storage of the buffer elements split into the shared chunks.
+/// Clones share the chunks, a chunk is copied on the first write into a shared one.
+#[derive(Clone)]
+struct Chunks<T: Default + Clone> {
+    chunks: Vec<Arc<Vec<T>>>,
+    len: usize,
+}
+
+impl<T: Default + Clone> Chunks<T> {
+    fn new(len: usize) -> Self {
+        let mut chunks = Self {
+            chunks: vec![],
+            len: 0
+        };
+
+        chunks.resize_with(len, Default::default);
+        chunks
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn resize_with<F: FnMut() -> T>(&mut self, len: usize, mut f: F) {
+        while self.len < len {
+            match self.chunks.last_mut() {
+                Some(chunk) if chunk.len() < CHUNK_SIZE => Arc::make_mut(chunk).push(f()),
+                _ => self.chunks.push(Arc::new(Vec::with_capacity(CHUNK_SIZE)))
+            }
+
+            self.len = (self.chunks.len() - 1) * CHUNK_SIZE + self.chunks.last().unwrap().len();
+        }
+    }
+
+    fn swap(&mut self, lhs: usize, rhs: usize) {
+        let (lhs_chunk, rhs_chunk) = (lhs / CHUNK_SIZE, rhs / CHUNK_SIZE);
+
+        if lhs_chunk == rhs_chunk {
+            Arc::make_mut(&mut self.chunks[lhs_chunk]).swap(lhs % CHUNK_SIZE, rhs % CHUNK_SIZE);
+        } else {
+            let (first, second) = (lhs_chunk.min(rhs_chunk), lhs_chunk.max(rhs_chunk));
+            let (head, tail) = self.chunks.split_at_mut(second);
+
+            let first_item = &mut Arc::make_mut(&mut head[first])[lhs.min(rhs) % CHUNK_SIZE];
+            let second_item = &mut Arc::make_mut(&mut tail[0])[lhs.max(rhs) % CHUNK_SIZE];
+
+            std::mem::swap(first_item, second_item);
+        }
+    }
+}
+
+impl<T: Default + Clone> Index<usize> for Chunks<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.chunks[index / CHUNK_SIZE][index % CHUNK_SIZE]
+    }
+}
+
+impl<T: Default + Clone> IndexMut<usize> for Chunks<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut Arc::make_mut(&mut self.chunks[index / CHUNK_SIZE])[index % CHUNK_SIZE]
+    }
+}
+
+/// Track internal buffer (ring buffer).
+/// Clones are cheap, they share the elements until they are changed.
+#[derive(Clone)]
 pub struct Buffer<T: Default + Clone> {
-    inner: Vec<T>,
+    inner: Chunks<T>,
     start_index: usize,
     len: usize,
     is_reversed: bool,
@@ -20,7 +92,7 @@ impl<T: Default + Clone> Buffer<T> {
         assert!(size > 0);
 
         Self {
-            inner: vec![Default::default(); size],
+            inner: Chunks::new(size),
             start_index: 0,
             len: 0,
             is_reversed: false,
@@ -63,7 +135,7 @@ impl<T: Default + Clone> Buffer<T> {
         }
     }
 
-    pub fn clear(&mut self) -> Truncated<T> {
+    pub fn clear(&mut self) -> Truncated<'_, T> {
         let old_start_index = self.start_index;
         let old_len = self.len;
 
@@ -108,7 +180,7 @@ impl<T: Default + Clone> Buffer<T> {
         }
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             buffer: self,
             index: 0,
@@ -158,7 +230,7 @@ impl<T: Default + Clone> Buffer<T> {
         Ok(())
     }
 
-    pub fn truncate_back(&mut self, mut index: usize) -> Truncated<T> {
+    pub fn truncate_back(&mut self, mut index: usize) -> Truncated<'_, T> {
         if self.is_empty() {
             return Truncated::empty(self);
         }
@@ -183,7 +255,7 @@ impl<T: Default + Clone> Buffer<T> {
         }
     }
 
-    pub fn truncate_forward(&mut self, index: usize) -> Truncated<T> {
+    pub fn truncate_forward(&mut self, index: usize) -> Truncated<'_, T> {
         if self.is_empty() {
             return Truncated::empty(self);
         }
@@ -216,16 +288,12 @@ impl<T: Default + Clone> Buffer<T> {
     }
 
     pub fn get(&self, index: usize) -> &T {
-        unsafe {
-            self.inner.get_unchecked(self.wrap_index(index))
-        }
+        &self.inner[self.wrap_index(index)]
     }
 
     pub fn get_mut(&mut self, index: usize) -> &mut T {
-        unsafe {
-            let index = self.wrap_index(index);
-            self.inner.get_unchecked_mut(index)
-        }
+        let index = self.wrap_index(index);
+        &mut self.inner[index]
     }
 
     fn wrap_index(&self, mut index: usize) -> usize {
@@ -329,7 +397,7 @@ impl<'rb, T: Default + Clone> Truncated<'rb, T> {
     }
 
     unsafe fn get_option_mut(&mut self, index: usize) -> Option<<Self as Iterator>::Item> {
-        let item = self.buffer.inner.get_unchecked_mut(index);
+        let item = &mut self.buffer.inner[index];
 
         Some(&mut *(item as *mut _))
     }
@@ -375,6 +443,7 @@ impl<'rb, T: Default + Clone> Iterator for Truncated<'rb, T> {
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero, clippy::map_clone)]
 mod tests {
     use crate::{
         buf::{
@@ -782,4 +851,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_shared_clone() -> Result<u32> {
+        let mut buffer = Buffer::<u32>::new(70);
+        buffer.try_append(0..50)?;
+
+        let mut clone = buffer.clone();
+        clone.truncate_forward(39);
+        clone.try_append(100..110)?;
+        clone[0] = 200;
+
+        buffer.grow();
+        buffer.try_append(50..100)?;
+
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), (0..100).collect::<Vec<_>>());
+        assert_eq!(
+            clone.iter().copied().collect::<Vec<_>>(),
+            std::iter::once(200).chain(1..40).chain(100..110).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,172 @@
+use crate::{
+    iter::NodeCursor,
+    Track,
+    TrackInterpolator,
+    TrackDerivative,
+    TrackIntegral,
+    TrackKey,
+    Error,
+    InterpolationResult,
+    Key,
+    KeyDistance,
+    Output,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Nodes around the `key`. The `key_end` belongs to the last segment.
+    pub(crate) fn segment_at(&self, key: &Key<I>) -> Option<(NodeCursor, NodeCursor)> {
+        if *key < self.key_start || *key > self.key_end {
+            return None;
+        }
+
+        let lhs = self.cursor_at(key)?;
+        match self.cursor_next(lhs) {
+            Some(rhs) => Some((lhs, rhs)),
+            None => self.cursor_prev(lhs).map(|lhs_prev| (lhs_prev, lhs))
+        }
+    }
+
+    /// Same as the `interpolate`, but accepts the `key_end` too. Looping is not taken into account.
+    pub(crate) fn interpolate_closed(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        if *key != self.key_end {
+            return self.interpolate_in_range(key);
+        }
+
+        let (lhs, rhs) = self.segment_at(key).ok_or(Error::KeyNotInRange)?;
+
+        self.interpolate_between(key, lhs, rhs, None)
+    }
+
+    /// Segment which contains the `key` wrapped by the loop.
+    /// The segment from the last node to the first one is returned with the loop length to shift the first node.
+    fn looped_segment_at(&self, key: &Key<I>) -> InterpolationResult<(Key<I>, Segment<I>), I::Error> {
+        match self.loop_length.clone() {
+            Some(loop_length) if !self.is_empty() => {
+                let key = self.wrap_key(key, &loop_length);
+                if key < self.key_end {
+                    let (lhs, rhs) = self.segment_at(&key).ok_or(Error::KeyNotInRange)?;
+
+                    Ok((key, (lhs, rhs, None)))
+                } else {
+                    let segment = (self.cursor_last().unwrap(), self.cursor_first().unwrap(), Some(loop_length));
+
+                    Ok((key, segment))
+                }
+            },
+            _ => {
+                let (lhs, rhs) = self.segment_at(key).ok_or(Error::KeyNotInRange)?;
+
+                Ok((key.clone(), (lhs, rhs, None)))
+            }
+        }
+    }
+}
+
+/// Left and right nodes with the shift of the right node keys
+type Segment<I> = (NodeCursor, NodeCursor, Option<KeyDistance<I>>);
+
+impl<I: TrackDerivative> Track<I> {
+    /// Derivative of the interpolated output at the `key`.
+    /// The `key` of a looping track is wrapped like in the `interpolate`.
+    pub fn derivative_at(&mut self, key: &Key<I>) -> InterpolationResult<I::Derivative, I::Error> {
+        let (key, (lhs, rhs, rhs_shift)) = self.looped_segment_at(key)?;
+
+        self.with_window(lhs, rhs, rhs_shift.as_ref(), |interpolator, window| {
+            interpolator.derivative_window(&key, window)
+        }).map_err(Error::Interpolation)
+    }
+}
+
+impl<I: TrackIntegral> Track<I> {
+    /// Integral of the interpolated output from the `from` key to the `to` key.
+    /// Sums over every range and not aligned sub-segment between the keys.
+    /// A looping track is integrated over every loop between the keys.
+    pub fn integrate(&mut self, from: &Key<I>, to: &Key<I>) -> InterpolationResult<I::Integral, I::Error> {
+        if from > to {
+            return Err(Error::KeyNotInRange);
+        }
+
+        match self.loop_length.clone() {
+            Some(loop_length) => self.integrate_looped(from, to, &loop_length),
+            None => self.integrate_in_range(from, to)
+        }
+    }
+
+    fn integrate_in_range(&mut self, from: &Key<I>, to: &Key<I>) -> InterpolationResult<I::Integral, I::Error> {
+        let (mut lhs, _) = self.segment_at(from).ok_or(Error::KeyNotInRange)?;
+        if *to > self.key_end {
+            return Err(Error::KeyNotInRange);
+        }
+
+        let mut integral = I::Integral::default();
+
+        while let Some(rhs) = self.cursor_next(lhs) {
+            let lhs_key = self.cursor_key(lhs);
+            let rhs_key = self.cursor_key(rhs);
+
+            if lhs_key >= *to {
+                break;
+            }
+
+            let begin = if *from > lhs_key { from } else { &lhs_key };
+            let end = if *to < rhs_key { to } else { &rhs_key };
+
+            let segment = self.with_window(lhs, rhs, None, |interpolator, window| {
+                interpolator.integrate_window(begin, end, window)
+            }).map_err(Error::Interpolation)?;
+
+            integral = self.interpolator.sum_integrals(integral, segment);
+            lhs = rhs;
+        }
+
+        Ok(integral)
+    }
+
+    fn integrate_looped(
+        &mut self,
+        from: &Key<I>,
+        to: &Key<I>,
+        loop_length: &KeyDistance<I>
+    ) -> InterpolationResult<I::Integral, I::Error> {
+        if self.is_empty() {
+            return Err(Error::KeyNotInRange);
+        }
+
+        let loop_end = self.key_start.add_distance(loop_length);
+        let mut begin = self.wrap_key(from, loop_length);
+        let mut distance = from.distance(to);
+        let mut integral = I::Integral::default();
+
+        loop {
+            let end = std::cmp::min(begin.add_distance(&distance), loop_end.clone());
+
+            if begin < self.key_end {
+                let in_range_end = std::cmp::min(end.clone(), self.key_end.clone());
+                let segment = self.integrate_in_range(&begin, &in_range_end)?;
+
+                integral = self.interpolator.sum_integrals(integral, segment);
+            }
+
+            if end > self.key_end {
+                let loop_begin = std::cmp::max(begin.clone(), self.key_end.clone());
+                let lhs = self.cursor_last().unwrap();
+                let rhs = self.cursor_first().unwrap();
+
+                let segment = self.with_window(lhs, rhs, Some(loop_length), |interpolator, window| {
+                    interpolator.integrate_window(&loop_begin, &end, window)
+                }).map_err(Error::Interpolation)?;
+
+                integral = self.interpolator.sum_integrals(integral, segment);
+            }
+
+            distance = distance - begin.distance(&end);
+            if distance <= KeyDistance::<I>::default() {
+                break;
+            }
+
+            begin = self.key_start.clone();
+        }
+
+        Ok(integral)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    ScalableDistance,
+    Error,
+    InterpolationResult,
+    Key,
+    KeyDistance,
+    Output,
+};
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator,
+    KeyDistance<I>: ScalableDistance
+{
+    /// Earliest key from the `from` to the `to` at which the `predicate` on the interpolated output is true.
+    /// Node keys are checked first, then the crossing is bisected inside the segment
+    /// until the keys around it are not farther than the `tolerance`.
+    /// The `to` can be the `key_end`. Nodes of a looping track are checked in every loop between the keys.
+    pub fn find_first<P>(
+        &mut self,
+        from: &Key<I>,
+        to: &Key<I>,
+        tolerance: &KeyDistance<I>,
+        mut predicate: P
+    ) -> InterpolationResult<Option<Key<I>>, I::Error>
+    where
+        P: FnMut(&Output<I>) -> bool
+    {
+        let is_in_range = self.loop_length.is_some() || (self.key_start <= *from && *to <= self.key_end);
+        if from > to || !is_in_range {
+            return Err(Error::KeyNotInRange);
+        }
+
+        if predicate(&self.interpolate_inclusive(from)?) {
+            return Ok(Some(from.clone()));
+        }
+
+        let mut lhs = from.clone();
+
+        loop {
+            let rhs = match self.next_node_key(&lhs) {
+                Some(key) if key < *to => key,
+                _ => to.clone()
+            };
+
+            if predicate(&self.interpolate_inclusive(&rhs)?) {
+                return self.bisect(lhs, rhs, tolerance, &mut predicate).map(Some);
+            }
+
+            if rhs == *to {
+                return Ok(None);
+            }
+
+            lhs = rhs;
+        }
+    }
+
+    /// Key of the first node after the `key`.
+    /// Nodes of a looping track repeat every loop, the first node is repeated at the loop end.
+    fn next_node_key(&self, key: &Key<I>) -> Option<Key<I>> {
+        match self.loop_length.clone() {
+            Some(loop_length) if !self.is_empty() => {
+                let wrapped = self.wrap_key(key, &loop_length);
+                let next_key = self.next_node_key_in_range(&wrapped)
+                    .unwrap_or_else(|| self.key_start.add_distance(&loop_length));
+
+                Some(next_key.add_distance(&wrapped.distance(key)))
+            },
+            _ => self.next_node_key_in_range(key)
+        }
+    }
+
+    fn next_node_key_in_range(&self, key: &Key<I>) -> Option<Key<I>> {
+        let mut cursor = match self.cursor_at(key) {
+            Some(cursor) => self.cursor_next(cursor)?,
+            None => self.cursor_first()?
+        };
+
+        while self.cursor_key(cursor) <= *key {
+            cursor = self.cursor_next(cursor)?;
+        }
+
+        Some(self.cursor_key(cursor))
+    }
+
+    /// Same as the `interpolate`, but accepts the `key_end` of a not looping track
+    fn interpolate_inclusive(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        if *key == self.key_end {
+            self.interpolate_closed(key)
+        } else {
+            self.interpolate(key)
+        }
+    }
+
+    /// Narrows the keys where the `predicate` is false at the `lhs` and true at the `rhs`
+    fn bisect<P>(
+        &mut self,
+        mut lhs: Key<I>,
+        mut rhs: Key<I>,
+        tolerance: &KeyDistance<I>,
+        predicate: &mut P
+    ) -> InterpolationResult<Key<I>, I::Error>
+    where
+        P: FnMut(&Output<I>) -> bool
+    {
+        while lhs.distance(&rhs) > *tolerance {
+            let middle = lhs.add_distance(&lhs.distance(&rhs).scale_f64(0.5));
+            if middle == lhs || middle == rhs {
+                break;
+            }
+
+            if predicate(&self.interpolate(&middle)?) {
+                rhs = middle;
+            } else {
+                lhs = middle;
+            }
+        }
+
+        Ok(rhs)
+    }
+}
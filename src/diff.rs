@@ -0,0 +1,77 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    TrackMutation,
+    ScalableDistance,
+    Error,
+    Result,
+    Key,
+    KeyDistance,
+    Data,
+    NotAlignedData,
+};
+
+/// Mutations which move a track from one version to another.
+/// Every change is kept as the mutation which made it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(
+    serialize = "TrackMutation<Key, Data, NA>: serde::Serialize",
+    deserialize = "TrackMutation<Key, Data, NA>: serde::Deserialize<'de>"
+)))]
+pub struct TrackDiff<Key: TrackKey, Data, NA> {
+    from_version: usize,
+    to_version: usize,
+    mutations: Vec<TrackMutation<Key, Data, NA>>,
+}
+
+impl<Key: TrackKey, Data, NA> TrackDiff<Key, Data, NA> {
+    pub fn from_version(&self) -> usize {
+        self.from_version
+    }
+
+    pub fn to_version(&self) -> usize {
+        self.to_version
+    }
+
+    pub fn mutations(&self) -> &[TrackMutation<Key, Data, NA>] {
+        &self.mutations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mutations.is_empty()
+    }
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Changes made after the `version`.
+    /// Returns `None` if the journal is disabled or doesn't keep the `version` anymore.
+    pub fn diff_since(&self, version: usize) -> Option<TrackDiff<Key<I>, Data<I>, NotAlignedData<I>>> {
+        let journal = self.journal.as_ref()?;
+        if version < self.journal_start || version > self.version {
+            return None;
+        }
+
+        Some(TrackDiff {
+            from_version: version,
+            to_version: self.version,
+            mutations: journal[version - self.journal_start..].to_vec()
+        })
+    }
+}
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator,
+    KeyDistance<I>: ScalableDistance
+{
+    /// Applies the `diff` made from the current version of the track
+    pub fn apply_diff(&mut self, diff: TrackDiff<Key<I>, Data<I>, NotAlignedData<I>>) -> Result<()> {
+        if diff.from_version != self.version {
+            return Err(Error::VersionMismatch);
+        }
+
+        self.replay(diff.mutations)
+    }
+}
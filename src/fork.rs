@@ -0,0 +1,81 @@
+use {
+    std::{
+        collections::HashMap,
+        sync::Arc,
+    },
+    crate::{
+        Track,
+        TrackInterpolator,
+        Error,
+        Result,
+    }
+};
+
+impl<I: TrackInterpolator + Clone> Track<I> {
+    /// Copy of the track which shares the nodes with this one until either of them changes them.
+    /// The fork has no aggregate indices, its journal is empty if this track has one.
+    pub fn fork(&self) -> Self {
+        Self {
+            interpolator: self.interpolator.clone(),
+            ranges: self.ranges.clone(),
+            buf: self.buf.clone(),
+            buf_index_base: self.buf_index_base,
+            aligned_step: self.aligned_step.clone(),
+            schedule: self.schedule.clone(),
+            next_step: self.next_step.clone(),
+            key_start: self.key_start.clone(),
+            key_end: self.key_end.clone(),
+            not_aligned_indices: self.not_aligned_indices.clone(),
+            loop_length: self.loop_length.clone(),
+            aggregates: HashMap::new(),
+            journal: self.journal.as_ref().map(|_| vec![]),
+            journal_start: self.version,
+            version: self.version,
+            identity: Arc::new(()),
+            fork_of: Some((self.identity.clone(), self.version)),
+            observers: vec![],
+            deferred_events: None,
+        }
+    }
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Replaces this track with the `fork` made from it.
+    /// Fails if the `fork` is not made from this track or this track was changed after the fork was made.
+    /// Mutations recorded by the fork journal are added to this track journal,
+    /// if the fork journal wasn't taken or trimmed. Otherwise this track journal is cleared.
+    pub fn commit(&mut self, mut fork: Self) -> Result<()> {
+        match fork.fork_of {
+            Some((ref identity, version)) if Arc::ptr_eq(identity, &self.identity) && version == self.version => {},
+            _ => return Err(Error::VersionMismatch)
+        }
+
+        let fork_journal = fork.journal
+            .take()
+            .filter(|_| fork.journal_start == self.version);
+        fork.aggregates = std::mem::take(&mut self.aggregates);
+        fork.journal = self.journal.take();
+        fork.journal_start = self.journal_start;
+        fork.identity = self.identity.clone();
+        fork.fork_of = self.fork_of.take();
+        fork.observers = std::mem::take(&mut self.observers);
+
+        let old_key_start = self.key_start.clone();
+        let old_key_end = self.key_end.clone();
+        *self = fork;
+
+        match (self.journal.as_mut(), fork_journal) {
+            (Some(journal), Some(fork_journal)) => journal.extend(fork_journal),
+            (Some(journal), None) => {
+                journal.clear();
+                self.journal_start = self.version;
+            },
+            (None, _) => {}
+        }
+
+        self.reindex_aggregates();
+        self.notify_replaced(old_key_start, old_key_end);
+
+        Ok(())
+    }
+}
@@ -14,6 +14,11 @@ pub trait TrackInterpolator {
     type Data: Debug + Default + Clone;
     type NotAlignedData: Debug + Deref<Target=Self::Data> + Clone;
     type Output;
+    type Error;
+
+    /// Count of the extra nodes on each side of the interpolated segment
+    /// which are passed to `interpolate_window`
+    const WINDOW: usize = 0;
 
     fn interpolate(
         &mut self, 
@@ -22,5 +27,131 @@ pub trait TrackInterpolator {
         lhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
         rhs_key: Self::Key,
         rhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
-    ) -> Self::Output;
-}
\ No newline at end of file
+    ) -> Result<Self::Output, Self::Error>;
+
+    /// Used instead of `interpolate` when the `WINDOW` is not zero
+    fn interpolate_window(
+        &mut self,
+        key: &Self::Key,
+        window: &TrackWindow<'_, Self::Key, Self::Data, Self::NotAlignedData>
+    ) -> Result<Self::Output, Self::Error> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+
+        self.interpolate(key, lhs_key.clone(), lhs, rhs_key.clone(), rhs)
+    }
+}
+
+/// Nodes around the interpolated segment in the key order.
+/// The window is shorter than requested near the track edges.
+pub struct TrackWindow<'t, Key, Data, NA>
+where
+    Key: TrackKey,
+    Data: Debug + Default + Clone,
+    NA: Debug + Deref<Target=Data> + Clone
+{
+    nodes: Vec<(Key, &'t TrackNode<Key, Data, NA>)>,
+    lhs_index: usize,
+}
+
+impl<'t, Key, Data, NA> TrackWindow<'t, Key, Data, NA>
+where
+    Key: TrackKey,
+    Data: Debug + Default + Clone,
+    NA: Debug + Deref<Target=Data> + Clone
+{
+    pub fn new(nodes: Vec<(Key, &'t TrackNode<Key, Data, NA>)>, lhs_index: usize) -> Self {
+        assert!(lhs_index + 1 < nodes.len());
+
+        Self {
+            nodes,
+            lhs_index
+        }
+    }
+
+    pub fn nodes(&self) -> &[(Key, &'t TrackNode<Key, Data, NA>)] {
+        &self.nodes
+    }
+
+    pub fn lhs_index(&self) -> usize {
+        self.lhs_index
+    }
+
+    pub fn lhs(&self) -> (&Key, &'t TrackNode<Key, Data, NA>) {
+        self.get(0).unwrap()
+    }
+
+    pub fn rhs(&self) -> (&Key, &'t TrackNode<Key, Data, NA>) {
+        self.get(1).unwrap()
+    }
+
+    /// Node at the `offset` from the left node of the segment
+    pub fn get(&self, offset: isize) -> Option<(&Key, &'t TrackNode<Key, Data, NA>)> {
+        let index = self.lhs_index as isize + offset;
+        if index < 0 {
+            return None;
+        }
+
+        self.nodes
+            .get(index as usize)
+            .map(|(key, node)| (key, *node))
+    }
+}
+
+/// Interpolator which can find the derivative of its output
+pub trait TrackDerivative: TrackInterpolator {
+    type Derivative;
+
+    fn derivative(
+        &mut self,
+        key: &Self::Key,
+        lhs_key: Self::Key,
+        lhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
+        rhs_key: Self::Key,
+        rhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
+    ) -> Result<Self::Derivative, Self::Error>;
+
+    /// Used instead of `derivative` when the `WINDOW` is not zero
+    fn derivative_window(
+        &mut self,
+        key: &Self::Key,
+        window: &TrackWindow<'_, Self::Key, Self::Data, Self::NotAlignedData>
+    ) -> Result<Self::Derivative, Self::Error> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+
+        self.derivative(key, lhs_key.clone(), lhs, rhs_key.clone(), rhs)
+    }
+}
+
+/// Interpolator which can integrate its output
+pub trait TrackIntegral: TrackInterpolator {
+    type Integral: Default;
+
+    /// Integral from the `from` key to the `to` key, both lying between the nodes
+    fn integrate(
+        &mut self,
+        from: &Self::Key,
+        to: &Self::Key,
+        lhs_key: Self::Key,
+        lhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
+        rhs_key: Self::Key,
+        rhs: &TrackNode<Self::Key, Self::Data, Self::NotAlignedData>,
+    ) -> Result<Self::Integral, Self::Error>;
+
+    /// Used instead of `integrate` when the `WINDOW` is not zero
+    fn integrate_window(
+        &mut self,
+        from: &Self::Key,
+        to: &Self::Key,
+        window: &TrackWindow<'_, Self::Key, Self::Data, Self::NotAlignedData>
+    ) -> Result<Self::Integral, Self::Error> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+
+        self.integrate(from, to, lhs_key.clone(), lhs, rhs_key.clone(), rhs)
+    }
+
+    /// Joins the integrals over two adjacent intervals
+    fn sum_integrals(&mut self, lhs: Self::Integral, rhs: Self::Integral) -> Self::Integral;
+}
@@ -0,0 +1,543 @@
+use {
+    std::{
+        ops::Deref,
+        marker::PhantomData,
+        fmt::Debug,
+        convert::Infallible,
+    },
+    crate::{
+        TrackInterpolator,
+        TrackDerivative,
+        TrackIntegral,
+        TrackKey,
+        MeasurableDistance,
+        TrackNode,
+        TrackWindow,
+    }
+};
+
+pub trait VectorSpace: Debug + Default + Clone {
+    fn add(&self, rhs: &Self) -> Self;
+
+    fn sub(&self, rhs: &Self) -> Self;
+
+    fn scale(&self, factor: f64) -> Self;
+}
+
+pub trait Lerp {
+    fn lerp(&self, rhs: &Self, t: f64) -> Self;
+}
+
+impl<V: VectorSpace> Lerp for V {
+    fn lerp(&self, rhs: &Self, t: f64) -> Self {
+        self.add(&rhs.sub(self).scale(t))
+    }
+}
+
+macro_rules! impl_vector_space {
+    ($($float:ty),+) => {
+        $(
+            impl VectorSpace for $float {
+                fn add(&self, rhs: &Self) -> Self {
+                    self + rhs
+                }
+
+                fn sub(&self, rhs: &Self) -> Self {
+                    self - rhs
+                }
+
+                fn scale(&self, factor: f64) -> Self {
+                    (*self as f64 * factor) as $float
+                }
+            }
+        )+
+    };
+}
+
+impl_vector_space!(f32, f64);
+
+/// Node of the `Hermite` interpolator.
+/// The tangent is the value change per key distance unit.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HermiteNode<V> {
+    pub value: V,
+    pub tangent: V,
+}
+
+type InterpolatorTypes<K, V, NA> = PhantomData<fn() -> (K, V, NA)>;
+
+/// Holds the value of the left node
+pub struct Step<K, V, NA>(InterpolatorTypes<K, V, NA>);
+
+pub struct Linear<K, V, NA>(InterpolatorTypes<K, V, NA>);
+
+/// Cubic Hermite spline
+pub struct Hermite<K, V, NA>(InterpolatorTypes<K, V, NA>);
+
+macro_rules! impl_interpolator_common {
+    ($($interpolator:ident),+) => {
+        $(
+            impl<K, V, NA> $interpolator<K, V, NA> {
+                pub fn new() -> Self {
+                    Self(PhantomData)
+                }
+            }
+
+            impl<K, V, NA> Default for $interpolator<K, V, NA> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl<K, V, NA> Clone for $interpolator<K, V, NA> {
+                fn clone(&self) -> Self {
+                    Self::new()
+                }
+            }
+        )+
+    };
+}
+
+/// Catmull-Rom spline.
+/// Tangents are taken from the neighbour nodes, so not aligned nodes are handled as non-uniform knots.
+/// A one-sided difference is used at the track edges.
+pub struct CatmullRom<K, V, NA>(InterpolatorTypes<K, V, NA>);
+
+impl_interpolator_common!(Step, Linear, Hermite, CatmullRom);
+
+/// Position of the `key` between the `lhs` and the `rhs` keys, from 0 to 1
+pub fn key_fraction<K>(key: &K, lhs: &K, rhs: &K) -> f64
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance
+{
+    let span = lhs.distance(rhs).to_f64();
+
+    if span == 0.0 {
+        0.0
+    } else {
+        lhs.distance(key).to_f64() / span
+    }
+}
+
+impl<K, V, NA> TrackInterpolator for Step<K, V, NA>
+where
+    K: TrackKey,
+    V: Debug + Default + Clone,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Key = K;
+    type Data = V;
+    type NotAlignedData = NA;
+    type Output = V;
+    type Error = Infallible;
+
+    fn interpolate(
+        &mut self,
+        _: &K,
+        _: K,
+        lhs: &TrackNode<K, V, NA>,
+        _: K,
+        _: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        Ok(V::clone(lhs))
+    }
+}
+
+impl<K, V, NA> TrackInterpolator for Linear<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: Lerp + Debug + Default + Clone,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Key = K;
+    type Data = V;
+    type NotAlignedData = NA;
+    type Output = V;
+    type Error = Infallible;
+
+    fn interpolate(
+        &mut self,
+        key: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let t = key_fraction(key, &lhs_key, &rhs_key);
+
+        Ok(lhs.lerp(rhs, t))
+    }
+}
+
+impl<K, V, NA> TrackInterpolator for Hermite<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=HermiteNode<V>> + Clone
+{
+    type Key = K;
+    type Data = HermiteNode<V>;
+    type NotAlignedData = NA;
+    type Output = V;
+    type Error = Infallible;
+
+    fn interpolate(
+        &mut self,
+        key: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, HermiteNode<V>, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, HermiteNode<V>, NA>,
+    ) -> Result<V, Infallible> {
+        let span = lhs_key.distance(&rhs_key).to_f64();
+        let t = key_fraction(key, &lhs_key, &rhs_key);
+
+        Ok(hermite(&lhs.value, &lhs.tangent.scale(span), &rhs.value, &rhs.tangent.scale(span), t))
+    }
+}
+
+impl<K, V, NA> TrackInterpolator for CatmullRom<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Key = K;
+    type Data = V;
+    type NotAlignedData = NA;
+    type Output = V;
+    type Error = Infallible;
+
+    const WINDOW: usize = 1;
+
+    fn interpolate(
+        &mut self,
+        key: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let window = TrackWindow::new(vec![(lhs_key, lhs), (rhs_key, rhs)], 0);
+
+        self.interpolate_window(key, &window)
+    }
+
+    fn interpolate_window(&mut self, key: &K, window: &TrackWindow<'_, K, V, NA>) -> Result<V, Infallible> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+        let (lhs_tangent, rhs_tangent) = catmull_rom_tangents(window);
+        let t = key_fraction(key, lhs_key, rhs_key);
+
+        Ok(hermite(lhs, &lhs_tangent, rhs, &rhs_tangent, t))
+    }
+}
+
+/// Tangents of the Catmull-Rom segment given for the unit segment
+fn catmull_rom_tangents<K, V, NA>(window: &TrackWindow<'_, K, V, NA>) -> (V, V)
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    let (lhs_key, lhs) = window.lhs();
+    let (rhs_key, rhs) = window.rhs();
+
+    let tangent = |(begin_key, begin): (&K, &TrackNode<K, V, NA>), (end_key, end): (&K, &TrackNode<K, V, NA>)| {
+        let span = begin_key.distance(end_key).to_f64();
+
+        end.sub(begin).scale(1.0 / span)
+    };
+
+    let lhs_tangent = tangent(window.get(-1).unwrap_or((lhs_key, lhs)), (rhs_key, rhs));
+    let rhs_tangent = tangent((lhs_key, lhs), window.get(2).unwrap_or((rhs_key, rhs)));
+
+    let span = lhs_key.distance(rhs_key).to_f64();
+
+    (lhs_tangent.scale(span), rhs_tangent.scale(span))
+}
+
+impl<K, V, NA> TrackDerivative for Step<K, V, NA>
+where
+    K: TrackKey,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Derivative = V;
+
+    fn derivative(
+        &mut self,
+        _: &K,
+        _: K,
+        _: &TrackNode<K, V, NA>,
+        _: K,
+        _: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        Ok(V::default())
+    }
+}
+
+impl<K, V, NA> TrackIntegral for Step<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Integral = V;
+
+    fn integrate(
+        &mut self,
+        from: &K,
+        to: &K,
+        _: K,
+        lhs: &TrackNode<K, V, NA>,
+        _: K,
+        _: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        Ok(lhs.scale(from.distance(to).to_f64()))
+    }
+
+    fn sum_integrals(&mut self, lhs: V, rhs: V) -> V {
+        lhs.add(&rhs)
+    }
+}
+
+impl<K, V, NA> TrackDerivative for Linear<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Derivative = V;
+
+    fn derivative(
+        &mut self,
+        _: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let span = lhs_key.distance(&rhs_key).to_f64();
+
+        Ok(rhs.sub(lhs).scale(1.0 / span))
+    }
+}
+
+impl<K, V, NA> TrackIntegral for Linear<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Integral = V;
+
+    fn integrate(
+        &mut self,
+        from: &K,
+        to: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let begin = lhs.lerp(rhs, key_fraction(from, &lhs_key, &rhs_key));
+        let end = lhs.lerp(rhs, key_fraction(to, &lhs_key, &rhs_key));
+
+        Ok(begin.add(&end).scale(from.distance(to).to_f64() / 2.0))
+    }
+
+    fn sum_integrals(&mut self, lhs: V, rhs: V) -> V {
+        lhs.add(&rhs)
+    }
+}
+
+impl<K, V, NA> TrackDerivative for Hermite<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=HermiteNode<V>> + Clone
+{
+    type Derivative = V;
+
+    fn derivative(
+        &mut self,
+        key: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, HermiteNode<V>, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, HermiteNode<V>, NA>,
+    ) -> Result<V, Infallible> {
+        let span = lhs_key.distance(&rhs_key).to_f64();
+        let t = key_fraction(key, &lhs_key, &rhs_key);
+
+        let derivative = hermite_derivative(
+            &lhs.value, &lhs.tangent.scale(span), &rhs.value, &rhs.tangent.scale(span), t
+        );
+
+        Ok(derivative.scale(1.0 / span))
+    }
+}
+
+impl<K, V, NA> TrackIntegral for Hermite<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=HermiteNode<V>> + Clone
+{
+    type Integral = V;
+
+    fn integrate(
+        &mut self,
+        from: &K,
+        to: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, HermiteNode<V>, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, HermiteNode<V>, NA>,
+    ) -> Result<V, Infallible> {
+        let span = lhs_key.distance(&rhs_key).to_f64();
+        let lhs_tangent = lhs.tangent.scale(span);
+        let rhs_tangent = rhs.tangent.scale(span);
+        let integral = |key| hermite_integral::<V>(
+            &lhs.value, &lhs_tangent, &rhs.value, &rhs_tangent, key_fraction(key, &lhs_key, &rhs_key)
+        );
+
+        Ok(integral(to).sub(&integral(from)).scale(span))
+    }
+
+    fn sum_integrals(&mut self, lhs: V, rhs: V) -> V {
+        lhs.add(&rhs)
+    }
+}
+
+impl<K, V, NA> TrackDerivative for CatmullRom<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Derivative = V;
+
+    fn derivative(
+        &mut self,
+        key: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let window = TrackWindow::new(vec![(lhs_key, lhs), (rhs_key, rhs)], 0);
+
+        self.derivative_window(key, &window)
+    }
+
+    fn derivative_window(&mut self, key: &K, window: &TrackWindow<'_, K, V, NA>) -> Result<V, Infallible> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+        let (lhs_tangent, rhs_tangent) = catmull_rom_tangents(window);
+        let span = lhs_key.distance(rhs_key).to_f64();
+        let t = key_fraction(key, lhs_key, rhs_key);
+
+        Ok(hermite_derivative::<V>(lhs, &lhs_tangent, rhs, &rhs_tangent, t).scale(1.0 / span))
+    }
+}
+
+impl<K, V, NA> TrackIntegral for CatmullRom<K, V, NA>
+where
+    K: TrackKey,
+    K::Distance: MeasurableDistance,
+    V: VectorSpace,
+    NA: Debug + Deref<Target=V> + Clone
+{
+    type Integral = V;
+
+    fn integrate(
+        &mut self,
+        from: &K,
+        to: &K,
+        lhs_key: K,
+        lhs: &TrackNode<K, V, NA>,
+        rhs_key: K,
+        rhs: &TrackNode<K, V, NA>,
+    ) -> Result<V, Infallible> {
+        let window = TrackWindow::new(vec![(lhs_key, lhs), (rhs_key, rhs)], 0);
+
+        self.integrate_window(from, to, &window)
+    }
+
+    fn integrate_window(&mut self, from: &K, to: &K, window: &TrackWindow<'_, K, V, NA>) -> Result<V, Infallible> {
+        let (lhs_key, lhs) = window.lhs();
+        let (rhs_key, rhs) = window.rhs();
+        let (lhs_tangent, rhs_tangent) = catmull_rom_tangents(window);
+        let span = lhs_key.distance(rhs_key).to_f64();
+        let integral = |key| hermite_integral::<V>(
+            lhs, &lhs_tangent, rhs, &rhs_tangent, key_fraction(key, lhs_key, rhs_key)
+        );
+
+        Ok(integral(to).sub(&integral(from)).scale(span))
+    }
+
+    fn sum_integrals(&mut self, lhs: V, rhs: V) -> V {
+        lhs.add(&rhs)
+    }
+}
+
+/// Cubic Hermite polynomial with tangents given for the unit segment
+fn hermite<V: VectorSpace>(p0: &V, m0: &V, p1: &V, m1: &V, t: f64) -> V {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+    let h10 = t3 - 2.0 * t2 + t;
+    let h01 = -2.0 * t3 + 3.0 * t2;
+    let h11 = t3 - t2;
+
+    p0.scale(h00)
+        .add(&m0.scale(h10))
+        .add(&p1.scale(h01))
+        .add(&m1.scale(h11))
+}
+
+/// Derivative of the `hermite` by the `t`
+fn hermite_derivative<V: VectorSpace>(p0: &V, m0: &V, p1: &V, m1: &V, t: f64) -> V {
+    let t2 = t * t;
+
+    let h00 = 6.0 * t2 - 6.0 * t;
+    let h10 = 3.0 * t2 - 4.0 * t + 1.0;
+    let h01 = -6.0 * t2 + 6.0 * t;
+    let h11 = 3.0 * t2 - 2.0 * t;
+
+    p0.scale(h00)
+        .add(&m0.scale(h10))
+        .add(&p1.scale(h01))
+        .add(&m1.scale(h11))
+}
+
+/// Integral of the `hermite` by the `t` from 0 to the `t`
+fn hermite_integral<V: VectorSpace>(p0: &V, m0: &V, p1: &V, m1: &V, t: f64) -> V {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    let t4 = t3 * t;
+
+    let h00 = t4 / 2.0 - t3 + t;
+    let h10 = t4 / 4.0 - 2.0 * t3 / 3.0 + t2 / 2.0;
+    let h01 = -t4 / 2.0 + t3;
+    let h11 = t4 / 4.0 - t3 / 3.0;
+
+    p0.scale(h00)
+        .add(&m0.scale(h10))
+        .add(&p1.scale(h01))
+        .add(&m1.scale(h11))
+}
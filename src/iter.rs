@@ -0,0 +1,194 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    TrackKeyDistance,
+    TrackNode,
+    Key,
+    Node,
+};
+
+/// Position of a node inside the track.
+/// A node shared by two ranges belongs to the range it begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct NodeCursor {
+    pub(crate) range_index: usize,
+    pub(crate) index: usize,
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    pub fn nodes(&self) -> Nodes<'_, I> {
+        Nodes {
+            track: self,
+            cursor: self.cursor_first(),
+        }
+    }
+
+    /// Node which key is equal to the `key`
+    pub fn node_at(&self, key: &Key<I>) -> Option<(Key<I>, &Node<I>)> {
+        self.cursor_at(key)
+            .map(|cursor| self.cursor_entry(cursor))
+            .filter(|(node_key, _)| node_key == key)
+    }
+
+    /// First node after the `key`
+    pub fn next_node(&self, key: &Key<I>) -> Option<(Key<I>, &Node<I>)> {
+        let cursor = match self.cursor_at(key) {
+            Some(cursor) => self.cursor_next(cursor),
+            None => self.cursor_first()
+        };
+
+        cursor.map(|cursor| self.cursor_entry(cursor))
+    }
+
+    /// Last node before the `key`
+    pub fn prev_node(&self, key: &Key<I>) -> Option<(Key<I>, &Node<I>)> {
+        let cursor = self.cursor_at(key)?;
+
+        if self.cursor_key(cursor) == *key {
+            self.cursor_prev(cursor).map(|cursor| self.cursor_entry(cursor))
+        } else {
+            Some(self.cursor_entry(cursor))
+        }
+    }
+
+    /// Node nearest to the `key`, the earlier one if both neighbours are at the same distance
+    pub fn nearest_node(&self, key: &Key<I>) -> Option<(Key<I>, &Node<I>)> {
+        let lhs = self.cursor_at(key).map(|cursor| self.cursor_entry(cursor));
+        let rhs = self.next_node(key);
+
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => {
+                let lhs_distance = lhs.0.distance(key).abs();
+                let rhs_distance = key.distance(&rhs.0).abs();
+
+                if lhs_distance <= rhs_distance {
+                    Some(lhs)
+                } else {
+                    Some(rhs)
+                }
+            },
+            (lhs, rhs) => lhs.or(rhs)
+        }
+    }
+
+    pub(crate) fn cursor_first(&self) -> Option<NodeCursor> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(NodeCursor {
+                range_index: 0,
+                index: self.buf_index_base
+            })
+        }
+    }
+
+    pub(crate) fn cursor_last(&self) -> Option<NodeCursor> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(NodeCursor {
+                range_index: self.ranges.len().saturating_sub(1),
+                index: self.buf_index_base + self.buf.len() - 1
+            })
+        }
+    }
+
+    /// Cursor of the last node which key is less or equal to the `key`
+    pub(crate) fn cursor_at(&self, key: &Key<I>) -> Option<NodeCursor> {
+        if self.is_empty() || *key < self.key_start {
+            None
+        } else if *key >= self.key_end || self.ranges.is_empty() {
+            self.cursor_last()
+        } else {
+            let range_index = self.range_index(key);
+            let nodes = self.find_nearby_nodes_in_range(range_index, key);
+
+            Some(NodeCursor {
+                range_index,
+                index: nodes.begin_index
+            })
+        }
+    }
+
+    pub(crate) fn cursor_next(&self, cursor: NodeCursor) -> Option<NodeCursor> {
+        if self.ranges.is_empty() {
+            return None;
+        }
+
+        let (_, end) = self.ranges[cursor.range_index];
+        if cursor.index == end {
+            None
+        } else if cursor.index + 1 == end && cursor.range_index + 1 < self.ranges.len() {
+            Some(NodeCursor {
+                range_index: cursor.range_index + 1,
+                index: end
+            })
+        } else {
+            Some(NodeCursor {
+                range_index: cursor.range_index,
+                index: cursor.index + 1
+            })
+        }
+    }
+
+    pub(crate) fn cursor_prev(&self, cursor: NodeCursor) -> Option<NodeCursor> {
+        if cursor.index == self.buf_index_base {
+            return None;
+        }
+
+        let (begin, _) = self.ranges[cursor.range_index];
+        if cursor.index == begin {
+            Some(NodeCursor {
+                range_index: cursor.range_index - 1,
+                index: cursor.index - 1
+            })
+        } else {
+            Some(NodeCursor {
+                range_index: cursor.range_index,
+                index: cursor.index - 1
+            })
+        }
+    }
+
+    pub(crate) fn cursor_key(&self, cursor: NodeCursor) -> Key<I> {
+        if self.ranges.is_empty() {
+            return self.key_start.clone();
+        }
+
+        let (begin, _) = self.ranges[cursor.range_index];
+        if cursor.index == begin {
+            self.range_index_to_key(cursor.range_index)
+        } else {
+            match self.cursor_node(cursor) {
+                TrackNode::NotAligned(node) => node.key.clone(),
+                TrackNode::Aligned(_) => self.schedule.range_key(cursor.range_index + 1)
+            }
+        }
+    }
+
+    pub(crate) fn cursor_node(&self, cursor: NodeCursor) -> &Node<I> {
+        &self.buf[cursor.index - self.buf_index_base]
+    }
+
+    fn cursor_entry(&self, cursor: NodeCursor) -> (Key<I>, &Node<I>) {
+        (self.cursor_key(cursor), self.cursor_node(cursor))
+    }
+}
+
+/// Iterator over the track nodes and their keys
+pub struct Nodes<'t, I: TrackInterpolator> {
+    track: &'t Track<I>,
+    cursor: Option<NodeCursor>,
+}
+
+impl<'t, I: TrackInterpolator> Iterator for Nodes<'t, I> {
+    type Item = (Key<I>, &'t Node<I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.cursor?;
+        self.cursor = self.track.cursor_next(cursor);
+
+        Some(self.track.cursor_entry(cursor))
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    ScalableDistance,
+    TrackMutation,
+    Result,
+    KeyDistance,
+    Mutation,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Starts recording the mutating calls of the track and its reversed view.
+    /// Replaying the journal on a new track with the same parameters reproduces this track.
+    pub fn enable_journal(&mut self) {
+        if self.journal.is_none() {
+            self.journal = Some(vec![]);
+            self.journal_start = self.version;
+        }
+    }
+
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+    }
+
+    pub fn journal(&self) -> Option<&[Mutation<I>]> {
+        self.journal.as_deref()
+    }
+
+    /// Takes the recorded mutations, the journal stays enabled
+    pub fn take_journal(&mut self) -> Vec<Mutation<I>> {
+        self.journal_start = self.version;
+        self.journal
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Drops the recorded mutations made before the `version`
+    pub fn trim_journal(&mut self, version: usize) {
+        if let Some(ref mut journal) = self.journal {
+            let trimmed = version.clamp(self.journal_start, self.version) - self.journal_start;
+
+            journal.drain(..trimmed);
+            self.journal_start += trimmed;
+        }
+    }
+
+    /// Count of the mutations made to the track
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub(crate) fn journal_entry<F>(&self, mutation: F) -> Option<Mutation<I>>
+    where
+        F: FnOnce() -> Mutation<I>
+    {
+        self.journal.as_ref().map(|_| mutation())
+    }
+
+    pub(crate) fn record_entry(&mut self, mutation: Option<Mutation<I>>) {
+        self.version += 1;
+
+        if let (Some(journal), Some(mutation)) = (self.journal.as_mut(), mutation) {
+            journal.push(mutation);
+        }
+    }
+
+    pub(crate) fn record<F>(&mut self, mutation: F)
+    where
+        F: FnOnce() -> Mutation<I>
+    {
+        let mutation = self.journal_entry(mutation);
+        self.record_entry(mutation);
+    }
+}
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator,
+    KeyDistance<I>: ScalableDistance
+{
+    /// Applies the mutations in order, stops at the first failed one
+    pub fn replay<L>(&mut self, log: L) -> Result<()>
+    where
+        L: IntoIterator<Item = Mutation<I>>
+    {
+        log.into_iter().try_for_each(|mutation| match mutation {
+            TrackMutation::ScaleTime(factor) => self.scale_time(factor),
+            mutation => self.apply_mutation(mutation)
+        })
+    }
+}
@@ -24,4 +24,17 @@ pub trait TrackKeyDistance: Debug
     fn scale(&self, factor: usize) -> Self;
 
     fn div_floor(&self, other: &Self) -> usize;
+}
+
+/// Distance which can be converted to a real number.
+/// Required by the interpolators which weigh the nodes by the key distances.
+pub trait MeasurableDistance: TrackKeyDistance {
+    fn to_f64(&self) -> f64;
+}
+
+/// Distance which can be multiplied by a real factor.
+/// Required by the `scale_time` and by the searches which split key ranges.
+pub trait ScalableDistance: TrackKeyDistance {
+    /// Product rounded to the nearest representable distance
+    fn scale_f64(&self, factor: f64) -> Self;
 }
\ No newline at end of file
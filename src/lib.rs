@@ -3,35 +3,143 @@ mod key;
 mod node;
 mod interpolator;
 mod search;
+mod schedule;
+mod iter;
+mod resample;
+mod transform;
+mod looping;
+mod reversed;
+mod window;
+mod calculus;
+mod crossing;
+mod approach;
+mod aggregate;
+mod lod;
+mod update;
+mod mutation;
+mod transaction;
+mod journal;
+mod diff;
+mod fork;
+mod snapshot;
+mod sync;
+mod observer;
+mod set;
+
+pub mod interpolators;
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy, clippy::iter_nth_zero)]
 mod tests;
 
 use {
     std::{
         ops::Index,
-        marker::PhantomData
+        marker::PhantomData,
+        convert::Infallible,
+        any::TypeId,
+        collections::{
+            HashMap,
+            VecDeque,
+        },
+        sync::Arc,
     },
     buf::Buffer,
+    iter::NodeCursor,
     search::search,
+    schedule::StepSchedule,
+    aggregate::SharedAggregateIndex,
+    observer::Observer,
+    transaction::TrackState,
 };
 
 pub use {
     key::{
         TrackKey,
         TrackKeyDistance,
+        MeasurableDistance,
+        ScalableDistance,
     },
-    node::TrackNode,
-    interpolator::TrackInterpolator,
+    node::{
+        TrackNode,
+        TrackNodeMut,
+    },
+    interpolator::{
+        TrackInterpolator,
+        TrackWindow,
+        TrackDerivative,
+        TrackIntegral,
+    },
+    iter::Nodes,
+    reversed::ReversedTrack,
+    aggregate::TrackAggregate,
+    mutation::TrackMutation,
+    transaction::Transaction,
+    diff::TrackDiff,
+    snapshot::TrackSnapshot,
+    sync::{
+        SyncTrack,
+        SyncTrackReader,
+        Invalidated,
+        WaitFor,
+    },
+    observer::TrackEvent,
+    set::TrackSet,
 };
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Result of the operations which run the interpolator with the error `E`
+pub type InterpolationResult<T, E> = std::result::Result<T, Error<E>>;
+
 #[derive(Debug, PartialEq, Eq)]
-pub enum Error {
+pub enum Error<E = Infallible> {
     Overflow,
     KeyNotInRange,
     KeyIsNotInInnerRange,
+    KeyIsNotAligned,
+    KeyIsBehindEnd,
+
+    /// Scaling makes the keys of the adjacent nodes equal
+    ScaleCollapsesKeys,
+
+    /// The diff or the fork is made from another version of the track, or from another track
+    VersionMismatch,
+
+    /// The key was changed since the previous read
+    Invalidated,
+
+    /// The interpolator failed to produce the output
+    Interpolation(E),
+}
+
+impl Error {
+    /// Same error for a track with the interpolator error `E`
+    pub fn widen<E>(self) -> Error<E> {
+        match self {
+            Error::Overflow => Error::Overflow,
+            Error::KeyNotInRange => Error::KeyNotInRange,
+            Error::KeyIsNotInInnerRange => Error::KeyIsNotInInnerRange,
+            Error::KeyIsNotAligned => Error::KeyIsNotAligned,
+            Error::KeyIsBehindEnd => Error::KeyIsBehindEnd,
+            Error::ScaleCollapsesKeys => Error::ScaleCollapsesKeys,
+            Error::VersionMismatch => Error::VersionMismatch,
+            Error::Invalidated => Error::Invalidated,
+            Error::Interpolation(infallible) => match infallible {},
+        }
+    }
+}
+
+/// Result of the `insert_not_aligned`.
+/// Keys after the node before the `key` up to the `old_key_end` are changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inserted<Key> {
+    pub old_key_end: Key,
+    pub new_key_end: Key,
+    pub key: Key,
+
+    /// Count of the nodes canceled by the insertion
+    pub canceled: usize,
 }
 
 type Key<I> = <I as TrackInterpolator>::Key;
@@ -48,6 +156,8 @@ type NotAlignedNode<I> = node::NotAlignedNode::<Key<I>, Data<I>, NotAlignedData<
 
 type Output<I> = <I as TrackInterpolator>::Output;
 
+type Mutation<I> = TrackMutation<Key<I>, Data<I>, NotAlignedData<I>>;
+
 type TrackRange = (usize, usize);
 
 struct NearbyNodes<I: TrackInterpolator> {
@@ -61,10 +171,31 @@ pub struct Track<I: TrackInterpolator> {
     interpolator: I,
     ranges: Buffer<TrackRange>,
     buf: Buffer<Node<I>>,
+    buf_index_base: usize,
     aligned_step: KeyDistance<I>,
+    schedule: StepSchedule<Key<I>>,
     next_step: KeyDistance<I>,
     key_start: Key<I>,
     key_end: Key<I>,
+
+    /// Absolute buffer indices of the not aligned nodes in the ascending order, so the `shift` visits only them.
+    /// Indices of the removed or replaced nodes are skipped.
+    not_aligned_indices: VecDeque<usize>,
+    loop_length: Option<KeyDistance<I>>,
+    aggregates: HashMap<TypeId, SharedAggregateIndex<Data<I>>>,
+    journal: Option<Vec<Mutation<I>>>,
+    journal_start: usize,
+    version: usize,
+
+    /// Identity checked by the `commit` of the forks
+    identity: Arc<()>,
+
+    /// Identity and version of the track this one is forked from
+    fork_of: Option<(Arc<()>, usize)>,
+    observers: Vec<Option<Observer<I>>>,
+
+    /// Events held back until the transaction is committed
+    deferred_events: Option<Vec<TrackEvent<Key<I>>>>,
 }
 
 impl<I: TrackInterpolator> Track<I> {
@@ -75,10 +206,22 @@ impl<I: TrackInterpolator> Track<I> {
             interpolator,
             ranges: Buffer::new(track_size - 1),
             buf: Buffer::new(track_size),
+            buf_index_base: 0,
             aligned_step: aligned_step.clone(),
+            schedule: StepSchedule::new(Key::<I>::default(), aligned_step.clone()),
             next_step: aligned_step,
             key_start: Key::<I>::default(),
             key_end: Key::<I>::default(),
+            not_aligned_indices: VecDeque::new(),
+            loop_length: None,
+            aggregates: HashMap::new(),
+            journal: None,
+            journal_start: 0,
+            version: 0,
+            identity: Arc::new(()),
+            fork_of: None,
+            observers: vec![],
+            deferred_events: None,
         }
     }
 
@@ -90,15 +233,37 @@ impl<I: TrackInterpolator> Track<I> {
         &self.key_end
     }
 
-    pub fn reset_track(&mut self, new_key_start: Key<I>) -> buf::Truncated<Node<I>> {
+    pub fn reset_track(&mut self, new_key_start: Key<I>) -> buf::Truncated<'_, Node<I>> {
+        self.record(|| TrackMutation::ResetTrack(new_key_start.clone()));
+        self.reset_track_helper(new_key_start)
+    }
+
+    fn reset_track_helper(&mut self, new_key_start: Key<I>) -> buf::Truncated<'_, Node<I>> {
         self.ranges.clear();
-        self.key_start = new_key_start.clone();
+        self.buf_index_base = 0;
+        self.not_aligned_indices.clear();
+        self.schedule.reset(new_key_start.clone(), self.aligned_step.clone());
+        let old_key_start = std::mem::replace(&mut self.key_start, new_key_start.clone());
         self.next_step = self.aligned_step.clone();
-        self.key_end = new_key_start;
+        let old_key_end = std::mem::replace(&mut self.key_end, new_key_start.clone());
+
+        self.notify(|| TrackEvent::Reset {
+            begin: old_key_start,
+            end: old_key_end,
+            key_start: new_key_start
+        });
+
         self.buf.clear()
     }
 
-    pub fn interpolate(&mut self, key: &Key<I>) -> Result<Output<I>> {
+    pub fn interpolate(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        match self.loop_length.clone() {
+            Some(loop_length) => self.interpolate_looped(key, &loop_length),
+            None => self.interpolate_in_range(key)
+        }
+    }
+
+    fn interpolate_in_range(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
         if *key < self.key_start || *key >= self.key_end {
             return Err(Error::KeyNotInRange);
         }
@@ -106,50 +271,90 @@ impl<I: TrackInterpolator> Track<I> {
         let range_index = self.range_index(key);
         let nodes = self.find_nearby_nodes_in_range(range_index, key);
 
-        let output = self.interpolator.interpolate(
+        if I::WINDOW > 0 {
+            let lhs = NodeCursor {
+                range_index,
+                index: nodes.begin_index
+            };
+            let rhs = self.cursor_next(lhs).unwrap();
+
+            return self.interpolate_between(key, lhs, rhs, None);
+        }
+
+        self.interpolator.interpolate(
             key, 
             nodes.begin_key, 
-            &self.buf[nodes.begin_index], 
+            &self.buf[nodes.begin_index - self.buf_index_base], 
             nodes.end_key, 
-            &self.buf[nodes.end_index]
-        );
-
-        Ok(output)
+            &self.buf[nodes.end_index - self.buf_index_base]
+        ).map_err(Error::Interpolation)
     }
 
     pub fn truncate_back(&mut self, key: &Key<I>) {
+        self.record(|| TrackMutation::TruncateBack(key.clone()));
+
         // If `key` is behind the `self.key_start` -- distance will be negative.
         // Negative distance might cause too big `range_index`.
         //
         // Also, those key value is meaningless for `truncate_back`
-        if !self.is_forward_key(&key) || self.ranges.len() < 2 {
+        if !self.is_forward_key(key) || self.ranges.len() < 2 {
             return;
         }
 
-        let old_begin = self.ranges.first().unwrap().0;
-        let range_index = self.range_index(&key);
+        let range_index = self.range_index(key);
         let removed_ranges = self.ranges.truncate_back(range_index);
         let removed_ranges = removed_ranges.len();
 
-        self.key_start = self.increase_key_by_step(&self.key_start, removed_ranges);
+        let new_key_start = self.schedule.range_key(removed_ranges);
+        let old_key_start = std::mem::replace(&mut self.key_start, new_key_start.clone());
+        self.schedule.truncate_back(removed_ranges);
 
-        let (begin, _) = self.ranges.first().unwrap();
-        self.buf.truncate_back(
-            self.wrap_buf_index(
-                *begin, old_begin
-            )
-        );
+        self.notify(|| TrackEvent::Truncated {
+            begin: old_key_start,
+            end: new_key_start
+        });
+
+        let (begin, _) = *self.ranges.first().unwrap();
+        self.buf.truncate_back(begin - self.buf_index_base);
+        self.buf_index_base = begin;
+    }
+
+    /// Changes the aligned step starting from the aligned `key` (which can't be behind the track end).
+    /// Previously set steps after the `key` are discarded.
+    pub fn set_aligned_step_from(&mut self, key: Key<I>, aligned_step: KeyDistance<I>) -> Result<()> {
+        if key < self.key_end {
+            return Err(Error::KeyIsBehindEnd);
+        }
+
+        let range_index = self.range_index(&key);
+        if self.schedule.range_key(range_index) != key {
+            return Err(Error::KeyIsNotAligned);
+        }
+
+        self.record(|| TrackMutation::SetAlignedStepFrom(key, aligned_step.clone()));
+        self.schedule.set_step_from(range_index, aligned_step);
+
+        let is_end_aligned = matches![self.node_end(), None | Some(Node::<I>::Aligned(_))];
+        self.next_step = self.step_to_next_aligned(is_end_aligned);
+
+        Ok(())
+    }
+
+    pub fn cancel_forward(&mut self, key: &Key<I>) -> buf::Truncated<'_, Node<I>> {
+        self.record(|| TrackMutation::CancelForward(key.clone()));
+        self.cancel_forward_helper(key)
     }
 
-    pub fn cancel_forward(&mut self, key: &Key<I>) -> buf::Truncated<Node<I>> {
+    fn cancel_forward_helper(&mut self, key: &Key<I>) -> buf::Truncated<'_, Node<I>> {
         if *key <= self.key_start {
-            return self.reset_track(
+            return self.reset_track_helper(
                 Key::<I>::default()
             );
         } else if *key > self.key_end || self.is_empty() {
             return buf::Truncated::empty(&mut self.buf)
         }
         
+        let old_key_end = self.key_end.clone();
         let index;
         let mut range_index;
 
@@ -187,45 +392,42 @@ impl<I: TrackInterpolator> Track<I> {
             }
         }
 
-        match self.buf[index] {
-            TrackNode::Aligned(_) => {
-                self.next_step = self.aligned_step.clone();
-                self.key_end = self.increase_key_by_step(&self.key_start, self.ranges.len());
-            },
-            TrackNode::NotAligned(ref node) => {
-                let range_index = self.range_index(&node.key);
-
-                let nearest_aligned_key = self.increase_key_by_step(
-                    &self.key_start, 
-                    range_index
-                );
+        let index = index - self.buf_index_base;
+        self.set_end_by_node(index);
 
-                self.key_end = node.key.clone();
-                let key_distance = nearest_aligned_key.distance(&self.key_end);
-                self.next_step = self.aligned_step.clone() - key_distance;
-            }
-        };
+        let key_end = self.key_end.clone();
+        self.notify(|| TrackEvent::Canceled {
+            begin: key_end,
+            end: old_key_end
+        });
 
         self.buf.truncate_forward(index)
     }
 
     pub fn push_aligned(&mut self, node: Data<I>) -> Result<()> {
+        let mutation = self.journal_entry(|| TrackMutation::PushAligned(node.clone()));
+        let old_key_end = self.key_end.clone();
+
         if self.is_empty() {
             self.buf.try_push(node.into()).unwrap();
-            return Ok(());
-        }
+            self.index_node_end();
+        } else {
+            if self.ranges.is_empty() {
+                debug_assert!(matches![self.node_end().unwrap(), Node::<I>::Aligned(_)]);
 
-        if self.ranges.is_empty() {
-            debug_assert!(matches![self.node_end().unwrap(), Node::<I>::Aligned(_)]);
+                self.buf.try_push(node.into()).unwrap();
+                self.ranges.try_push(self.first_range()).unwrap();
+            } else {
+                self.push_helper(node.into())?;
+            }
 
-            self.buf.try_push(node.into()).unwrap();
-            self.ranges.try_push((0, 1)).unwrap();
-        } else {
-            self.push_helper(node.into())?;
+            self.index_node_end();
+            self.key_end = self.key_end.add_distance(&self.next_step);
+            self.next_step = self.schedule.step(self.ranges.len()).clone();
         }
 
-        self.key_end = self.key_end.add_distance(&self.next_step);
-        self.next_step = self.aligned_step.clone();
+        self.record_entry(mutation);
+        self.notify_appended(old_key_end);
 
         Ok(())
     }
@@ -235,7 +437,7 @@ impl<I: TrackInterpolator> Track<I> {
         key: Key<I>, 
         node: NotAlignedData<I>,
         mut handler: Handler
-    ) -> Result<()> 
+    ) -> Result<Inserted<Key<I>>> 
     where
         Handler: FnMut(&mut Node<I>)
     {
@@ -243,10 +445,17 @@ impl<I: TrackInterpolator> Track<I> {
             return Err(Error::KeyIsNotInInnerRange);
         }
 
-        let mut canceled_nodes = self.cancel_forward(&key);
-        let nearest_canceled_node = canceled_nodes.nth(0).unwrap().clone();
+        let mutation = self.journal_entry(
+            || TrackMutation::InsertNotAligned(key.clone(), node.clone())
+        );
+        let old_key_end = self.key_end.clone();
+
+        let mut canceled_nodes = self.cancel_forward_helper(&key);
+        let nearest_canceled_node = canceled_nodes.peek_first().unwrap().clone();
+        let mut canceled = 0;
         for node in canceled_nodes {
-            handler(node)
+            handler(node);
+            canceled += 1;
         }
 
         let (canceled_node, canceled_key) = match nearest_canceled_node {
@@ -267,7 +476,7 @@ impl<I: TrackInterpolator> Track<I> {
         let node_key = key.clone();
         let not_aligned_node = NotAlignedNode::<I> {
             node,
-            key,
+            key: key.clone(),
             canceled_node,
             canceled_key,
             phantom: PhantomData
@@ -277,16 +486,25 @@ impl<I: TrackInterpolator> Track<I> {
             debug_assert!(matches![self.node_end().unwrap(), Node::<I>::Aligned(_)]);
 
             self.buf.try_push(not_aligned_node.into()).unwrap();
-            self.ranges.try_push((0, 1)).unwrap();
+            self.ranges.try_push(self.first_range()).unwrap();
         } else {
             self.push_helper(not_aligned_node.into())?;
         }
 
+        self.index_node_end();
+        self.index_not_aligned_end();
         let key_distance = self.key_end.distance(&node_key);
         self.next_step = self.next_step.clone() - key_distance;
-        self.key_end = node_key;
-
-        Ok(())
+        let canceled_key_end = std::mem::replace(&mut self.key_end, node_key.clone());
+        self.record_entry(mutation);
+        self.notify_appended(canceled_key_end);
+
+        Ok(Inserted {
+            old_key_end,
+            new_key_end: node_key.clone(),
+            key: node_key,
+            canceled
+        })
     }
 
     fn push_helper(&mut self, node: Node<I>) -> Result<()> {
@@ -312,6 +530,10 @@ impl<I: TrackInterpolator> Track<I> {
         Ok(())
     }
 
+    fn first_range(&self) -> TrackRange {
+        (self.buf_index_base, self.buf_index_base + 1)
+    }
+
     fn try_push(&mut self, node: Node::<I>) -> Result<()> {
         if let Err(buf::Error::Overflow(node)) = self.buf.try_push(node) {
             self.force_push(node)?;
@@ -349,8 +571,8 @@ impl<I: TrackInterpolator> Track<I> {
         let range = self.ranges[range_index];
 
         let begin_key = self.range_index_to_key(range_index);
-        let end_key = match self.buf[range.1] {
-            Node::<I>::Aligned(_) => begin_key.add_distance(&self.aligned_step),
+        let end_key = match self.buf[range.1 - self.buf_index_base] {
+            Node::<I>::Aligned(_) => self.schedule.range_key(range_index + 1),
             Node::<I>::NotAligned(ref node) => node.key.clone()
         };
 
@@ -358,6 +580,7 @@ impl<I: TrackInterpolator> Track<I> {
 
         let range_adapter = TrackRangeAdapter::<I>::new(
             &self.buf, 
+            self.buf_index_base,
             range, 
             begin_key, 
             end_key.clone()
@@ -366,7 +589,7 @@ impl<I: TrackInterpolator> Track<I> {
         let (begin_index, begin_key) = search(&range_adapter, range.0, range.1, key);
         let end_index = begin_index + 1;
         let begin_key = begin_key.clone();
-        let end_key = match self.buf[end_index] {
+        let end_key = match self.buf[end_index - self.buf_index_base] {
             Node::<I>::Aligned(_) => end_key,
             Node::<I>::NotAligned(ref node) => node.key.clone()
         };
@@ -379,6 +602,49 @@ impl<I: TrackInterpolator> Track<I> {
         }
     }
 
+    /// Restores the `key_end` and the `next_step` as if the node at the `index` is the last one
+    fn set_end_by_node(&mut self, index: usize) {
+        let not_aligned_key = match self.buf[index] {
+            TrackNode::Aligned(_) => None,
+            TrackNode::NotAligned(ref node) => Some(node.key.clone())
+        };
+
+        let is_end_aligned = not_aligned_key.is_none();
+        self.key_end = match not_aligned_key {
+            None => self.schedule.range_key(self.ranges.len()),
+            Some(key) => key
+        };
+
+        self.next_step = self.step_to_next_aligned(is_end_aligned);
+    }
+
+    /// Distance from the `key_end` to the key of the next aligned node.
+    /// A not aligned end belongs to the last range, so the next aligned key is the end of that range.
+    fn step_to_next_aligned(&self, is_end_aligned: bool) -> KeyDistance<I> {
+        let ranges = self.ranges.len();
+
+        if is_end_aligned {
+            self.schedule.step(ranges).clone()
+        } else {
+            self.key_end.distance(&self.schedule.range_key(ranges))
+        }
+    }
+
+    /// Records the index of the not aligned node just pushed to the end
+    fn index_not_aligned_end(&mut self) {
+        let index = self.buf_index_base + self.buf.len() - 1;
+
+        while matches![self.not_aligned_indices.back(), Some(back) if *back >= index] {
+            self.not_aligned_indices.pop_back();
+        }
+
+        while matches![self.not_aligned_indices.front(), Some(front) if *front < self.buf_index_base] {
+            self.not_aligned_indices.pop_front();
+        }
+
+        self.not_aligned_indices.push_back(index);
+    }
+
     fn is_key_in_inner_range(&self, key: &Key<I>) -> bool {
         self.key_start < *key && * key < self.key_end
     }
@@ -388,32 +654,19 @@ impl<I: TrackInterpolator> Track<I> {
     }
 
     fn range_index(&self, key: &Key<I>) -> usize {
-        self.key_start.distance(key)
-            .div_floor(&self.aligned_step)
-    }
-
-    fn increase_key_by_step(&self, key: &Key<I>, steps: usize) -> Key<I> {
-        key.add_distance(
-            &self.aligned_step.scale(steps)
-        )
+        self.schedule.range_index(key)
     }
 
     fn range_index_to_key(&self, range_index: usize) -> Key<I> {
         assert!(range_index < self.ranges.len());
 
-        self.increase_key_by_step(&self.key_start, range_index)
-    }
-
-    fn wrap_buf_index(&self, buf_index: usize, begin_index: usize) -> usize {
-        assert!(buf_index >= begin_index);
-        let index = buf_index - begin_index;
-
-        self.buf.wrap_raw_index(index)
+        self.schedule.range_key(range_index)
     }
 }
 
 struct TrackRangeAdapter<'b, I: TrackInterpolator> {
     buf: &'b Buffer<Node<I>>,
+    index_base: usize,
     range: TrackRange,
     left_key: Key<I>,
     right_key: Key<I>
@@ -422,12 +675,14 @@ struct TrackRangeAdapter<'b, I: TrackInterpolator> {
 impl<'b, I: TrackInterpolator> TrackRangeAdapter<'b, I> {
     fn new(
         buf: &'b Buffer<Node<I>>, 
+        index_base: usize,
         range: TrackRange, 
         left_key: Key<I>,
         right_key: Key<I>
     ) -> Self {
         Self {
             buf,
+            index_base,
             range,
             left_key,
             right_key
@@ -444,7 +699,7 @@ impl<'b, I: TrackInterpolator> Index<usize> for TrackRangeAdapter<'b, I> {
         } else if index == self.range.1 {
             &self.right_key
         } else {
-            match self.buf[index] {
+            match self.buf[index - self.index_base] {
                 TrackNode::NotAligned(ref node) => &node.key,
                 TrackNode::Aligned(_) => panic!("unexpected aligned node")
             }
@@ -0,0 +1,64 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackAggregate,
+    Key,
+    Data,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// At most `max_points` aggregates of the nodes from the `from` to the `to` paired with their first keys.
+    /// Nodes are summarized by the buckets of 2^k aligned ranges, where the k is the least one
+    /// giving no more than `max_points` buckets. Buckets are aligned to the track start,
+    /// so the first and the last ones can include nodes outside of the keys.
+    ///
+    /// The index of the `A` must be enabled by the `enable_aggregate`, otherwise nothing is returned.
+    pub fn sample_lod<A>(&self, from: &Key<I>, to: &Key<I>, max_points: usize) -> Vec<(Key<I>, A::Aggregate)>
+    where
+        A: TrackAggregate<Data = Data<I>> + 'static
+    {
+        let tree = match self.aggregate_tree::<A>() {
+            Some(tree) => tree,
+            None => return vec![]
+        };
+
+        if self.is_empty() || max_points == 0 || from > to || *to < self.key_start || *from > self.key_end {
+            return vec![];
+        }
+
+        if self.ranges.is_empty() {
+            let index = self.buf_index_base;
+
+            return vec![(self.key_start.clone(), tree.query_nodes(index, index))];
+        }
+
+        let ranges_count = self.ranges.len();
+        let first = if *from <= self.key_start {
+            0
+        } else {
+            self.range_index(from).min(ranges_count - 1)
+        };
+        let last = self.range_index(to).min(ranges_count - 1);
+
+        let mut bucket_size = 1;
+        while last / bucket_size - first / bucket_size >= max_points {
+            bucket_size *= 2;
+        }
+
+        (first / bucket_size..=last / bucket_size)
+            .map(|bucket| {
+                let begin = bucket * bucket_size;
+                let end = ranges_count.min(begin + bucket_size);
+
+                let (first_node, _) = self.ranges[begin];
+                let last_node = if end == ranges_count {
+                    self.ranges[end - 1].1
+                } else {
+                    self.ranges[end].0 - 1
+                };
+
+                (self.range_index_to_key(begin), tree.query_nodes(first_node, last_node))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    TrackKeyDistance,
+    TrackMutation,
+    Error,
+    InterpolationResult,
+    Key,
+    KeyDistance,
+    Output,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Makes the track cyclic: keys are wrapped into `[key_start, key_start + loop_length)` before lookup.
+    /// Keys between the track end and the loop end are interpolated between the last and the first nodes.
+    pub fn set_loop_length(&mut self, loop_length: Option<KeyDistance<I>>) {
+        if let Some(ref loop_length) = loop_length {
+            assert!(*loop_length > KeyDistance::<I>::default());
+        }
+
+        self.record(|| TrackMutation::SetLoopLength(loop_length.clone()));
+        self.loop_length = loop_length;
+    }
+
+    pub fn loop_length(&self) -> Option<&KeyDistance<I>> {
+        self.loop_length.as_ref()
+    }
+
+    pub(crate) fn interpolate_looped(
+        &mut self, 
+        key: &Key<I>, 
+        loop_length: &KeyDistance<I>
+    ) -> InterpolationResult<Output<I>, I::Error> {
+        if self.is_empty() {
+            return Err(Error::KeyNotInRange);
+        }
+
+        let key = self.wrap_key(key, loop_length);
+        if key < self.key_end {
+            return self.interpolate_in_range(&key);
+        }
+
+        let lhs = self.cursor_last().unwrap();
+        let rhs = self.cursor_first().unwrap();
+
+        self.interpolate_between(&key, lhs, rhs, Some(loop_length))
+    }
+
+    pub(crate) fn wrap_key(&self, key: &Key<I>, loop_length: &KeyDistance<I>) -> Key<I> {
+        let distance = self.key_start.distance(key);
+
+        let distance = if distance >= KeyDistance::<I>::default() {
+            let loops = distance.div_floor(loop_length);
+
+            distance - loop_length.scale(loops)
+        } else {
+            let loops = (-distance.clone()).div_floor(loop_length) + 1;
+            let distance = distance + loop_length.scale(loops);
+
+            if distance >= *loop_length {
+                distance - loop_length.clone()
+            } else {
+                distance
+            }
+        };
+
+        self.key_start.add_distance(&distance)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    TrackNodeMut,
+    Error,
+    Result,
+    Mutation,
+};
+
+/// Mutating call of a track with its arguments
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrackMutation<Key: TrackKey, Data, NA> {
+    ResetTrack(Key),
+    PushAligned(Data),
+    InsertNotAligned(Key, NA),
+    CancelForward(Key),
+    TruncateBack(Key),
+    SetAlignedStepFrom(Key, Key::Distance),
+
+    /// Data of the aligned node at the key set by the `update`
+    Update(Key, Data),
+
+    /// Data of the not aligned node at the key set by the `update`
+    UpdateNotAligned(Key, NA),
+    Shift(Key::Distance),
+    SetLoopLength(Option<Key::Distance>),
+    ReversedTruncateBack(Key),
+    ReversedCancelForward(Key),
+
+    /// Factor of the `scale_time`
+    ScaleTime(f64),
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    pub(crate) fn apply_mutation(&mut self, mutation: Mutation<I>) -> Result<()> {
+        match mutation {
+            TrackMutation::ResetTrack(key) => {
+                self.reset_track(key);
+
+                Ok(())
+            },
+            TrackMutation::PushAligned(node) => self.push_aligned(node),
+            TrackMutation::InsertNotAligned(key, node) => self.insert_not_aligned(key, node, |_| {}).map(|_| ()),
+            TrackMutation::CancelForward(key) => {
+                self.cancel_forward(&key);
+
+                Ok(())
+            },
+            TrackMutation::TruncateBack(key) => {
+                self.truncate_back(&key);
+
+                Ok(())
+            },
+            TrackMutation::SetAlignedStepFrom(key, aligned_step) => self.set_aligned_step_from(key, aligned_step),
+            TrackMutation::Update(key, data) => {
+                let is_updated = self.update(&key, |node| match node {
+                    TrackNodeMut::Aligned(node) => {
+                        *node = data;
+                        true
+                    },
+                    TrackNodeMut::NotAligned(_) => false
+                });
+
+                is_updated.filter(|is_updated| *is_updated)
+                    .map(|_| ())
+                    .ok_or(Error::KeyNotInRange)
+            },
+            TrackMutation::UpdateNotAligned(key, data) => {
+                let is_updated = self.update(&key, |node| match node {
+                    TrackNodeMut::NotAligned(node) => {
+                        *node = data;
+                        true
+                    },
+                    TrackNodeMut::Aligned(_) => false
+                });
+
+                is_updated.filter(|is_updated| *is_updated)
+                    .map(|_| ())
+                    .ok_or(Error::KeyNotInRange)
+            },
+            TrackMutation::Shift(distance) => {
+                self.shift(&distance);
+
+                Ok(())
+            },
+            TrackMutation::SetLoopLength(loop_length) => {
+                self.set_loop_length(loop_length);
+
+                Ok(())
+            },
+            TrackMutation::ReversedTruncateBack(key) => {
+                self.reversed().truncate_back(&key);
+
+                Ok(())
+            },
+            TrackMutation::ReversedCancelForward(key) => {
+                self.reversed().cancel_forward(&key);
+
+                Ok(())
+            },
+            TrackMutation::ScaleTime(_) => unreachable!("the scaling needs the scalable distance, it is applied by the replay")
+        }
+    }
+}
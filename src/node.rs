@@ -92,6 +92,7 @@ where
         &self.canceled_node
     }
 
+    /// Key of the canceled node
     pub fn canceled_key(&self) -> &Key {
         &self.canceled_key
     }
@@ -108,4 +109,12 @@ where
     fn deref(&self) -> &Self::Target {
         &self.node
     }
-}
\ No newline at end of file
+}
+
+/// Mutable access to the data of a track node.
+/// The node kind and key can't be changed through it.
+#[derive(Debug)]
+pub enum TrackNodeMut<'n, Data, NA> {
+    Aligned(&'n mut Data),
+    NotAligned(&'n mut NA)
+}
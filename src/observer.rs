@@ -0,0 +1,98 @@
+use {
+    std::sync::{Mutex, PoisonError},
+    crate::{
+        Track,
+        TrackInterpolator,
+        Key,
+    }
+};
+
+/// Change of a track, keys are from the `begin` to the `end` (not including)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackEvent<Key> {
+    /// Nodes were added to the track end, the `begin` is the previous end
+    Appended { begin: Key, end: Key },
+
+    /// Nodes were canceled from the track end, the `end` is the previous end
+    Canceled { begin: Key, end: Key },
+
+    /// Nodes were dropped from the track start, the `begin` is the previous start
+    Truncated { begin: Key, end: Key },
+
+    /// Every node was dropped, the track starts from the `key_start` now
+    Reset { begin: Key, end: Key, key_start: Key },
+
+    /// Data of the node at the `key` was changed in place
+    Updated { begin: Key, end: Key, key: Key },
+}
+
+/// The mutex is never locked, it only keeps the track `Sync` for the observers which are not
+pub(crate) type Observer<I> = Mutex<Box<dyn FnMut(&TrackEvent<Key<I>>) + Send>>;
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Calls the `observer` on every change of the track nodes.
+    /// Returns the id for the `remove_observer`.
+    pub fn add_observer<F>(&mut self, observer: F) -> usize
+    where
+        F: FnMut(&TrackEvent<Key<I>>) + Send + 'static
+    {
+        self.observers.push(Some(Mutex::new(Box::new(observer))));
+        self.observers.len() - 1
+    }
+
+    /// Returns `false` if there is no observer with the `id`
+    pub fn remove_observer(&mut self, id: usize) -> bool {
+        self.observers
+            .get_mut(id)
+            .and_then(Option::take)
+            .is_some()
+    }
+
+    pub(crate) fn notify<F>(&mut self, event: F)
+    where
+        F: FnOnce() -> TrackEvent<Key<I>>
+    {
+        if self.observers.iter().all(Option::is_none) {
+            return;
+        }
+
+        let event = event();
+        match self.deferred_events {
+            Some(ref mut events) => events.push(event),
+            None => for observer in self.observers.iter_mut().flatten() {
+                let observer = observer.get_mut().unwrap_or_else(PoisonError::into_inner);
+                observer(&event);
+            }
+        }
+    }
+
+    pub(crate) fn notify_appended(&mut self, old_key_end: Key<I>) {
+        let key_end = self.key_end.clone();
+        if old_key_end == key_end {
+            return;
+        }
+
+        self.notify(|| TrackEvent::Appended {
+            begin: old_key_end,
+            end: key_end
+        });
+    }
+
+    /// Notifies about the track which nodes were replaced all at once
+    pub(crate) fn notify_replaced(&mut self, old_key_start: Key<I>, old_key_end: Key<I>) {
+        let key_start = self.key_start.clone();
+        let key_end = self.key_end.clone();
+
+        self.notify(|| TrackEvent::Reset {
+            begin: old_key_start,
+            end: old_key_end,
+            key_start: key_start.clone()
+        });
+        if key_start != key_end {
+            self.notify(|| TrackEvent::Appended {
+                begin: key_start,
+                end: key_end
+            });
+        }
+    }
+}
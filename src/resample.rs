@@ -0,0 +1,111 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    TrackKeyDistance,
+    TrackNode,
+    Error,
+    InterpolationResult,
+    Key,
+    KeyDistance,
+    Data,
+    NotAlignedData,
+    Output,
+};
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator + Clone,
+    NotAlignedData<I>: From<Data<I>>
+{
+    /// Builds a new track with the `aligned_step` by interpolating this one at the new aligned keys.
+    /// Not aligned nodes are kept, unless their keys are aligned in the new track.
+    /// If the `key_end` is not aligned in the new track, the end node is kept as a not aligned one.
+    pub fn resample<F>(&mut self, aligned_step: KeyDistance<I>, mut to_data: F) -> InterpolationResult<Self, I::Error>
+    where
+        F: FnMut(Output<I>) -> Data<I>
+    {
+        let key_start = self.key_start.clone();
+        let is_aligned = |key: &Key<I>| {
+            let steps = key_start.distance(key).div_floor(&aligned_step);
+
+            key_start.add_distance(&aligned_step.scale(steps)) == *key
+        };
+
+        let not_aligned = self.nodes()
+            .filter_map(|(key, node)| match node {
+                TrackNode::NotAligned(node) if !is_aligned(&key) => Some((key, node.node.clone())),
+                _ => None
+            })
+            .collect::<Vec<_>>();
+
+        let steps = self.key_start.distance(&self.key_end).div_floor(&aligned_step);
+        let track_size = steps + not_aligned.len() + 3;
+
+        let mut track = Self::new(self.interpolator.clone(), track_size, aligned_step.clone());
+        track.reset_track(self.key_start.clone());
+
+        if self.is_empty() {
+            return Ok(track);
+        }
+
+        let mut not_aligned = not_aligned.into_iter().peekable();
+        let mut key = self.key_start.clone();
+        let data = self.resample_data(&key, &mut to_data)?;
+        track.push_aligned(data).map_err(Error::widen)?;
+
+        loop {
+            let next_key = key.add_distance(&aligned_step);
+            let has_not_aligned = matches![not_aligned.peek(), Some((key, _)) if *key < next_key];
+
+            if next_key > self.key_end && !has_not_aligned {
+                break;
+            }
+
+            let next_data = self.resample_data(&next_key, &mut to_data)?;
+            track.push_aligned(next_data.clone()).map_err(Error::widen)?;
+
+            let mut is_next_pushed = true;
+            while let Some((key, node)) = not_aligned.next_if(|(key, _)| *key < next_key) {
+                if !is_next_pushed {
+                    track.push_aligned(next_data.clone()).map_err(Error::widen)?;
+                }
+
+                track.insert_not_aligned(key, node, |_| {}).map_err(Error::widen)?;
+                is_next_pushed = false;
+            }
+
+            if next_key > self.key_end {
+                break;
+            }
+
+            if !is_next_pushed {
+                track.push_aligned(next_data).map_err(Error::widen)?;
+            }
+
+            key = next_key;
+        }
+
+        if track.key_end < self.key_end {
+            let data = Data::<I>::clone(self.node_end().unwrap());
+
+            track.push_aligned(data.clone()).map_err(Error::widen)?;
+            track.insert_not_aligned(self.key_end.clone(), data.into(), |_| {}).map_err(Error::widen)?;
+        }
+
+        Ok(track)
+    }
+
+    fn resample_data<F>(&mut self, key: &Key<I>, to_data: &mut F) -> InterpolationResult<Data<I>, I::Error>
+    where
+        F: FnMut(Output<I>) -> Data<I>
+    {
+        if *key < self.key_end {
+            self.interpolate(key).map(to_data)
+        } else {
+            let node = self.node_end().unwrap();
+
+            Ok(Data::<I>::clone(node))
+        }
+    }
+}
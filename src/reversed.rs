@@ -0,0 +1,170 @@
+use crate::{
+    buf,
+    Track,
+    TrackInterpolator,
+    TrackNode,
+    TrackEvent,
+    TrackMutation,
+    Error,
+    InterpolationResult,
+    Key,
+    Data,
+    Node,
+    Output,
+};
+
+/// Track view for the backward playback.
+/// The view starts at the track end and finishes at the track start,
+/// so its interpolation range is `(key_start, key_end]` of the track.
+pub struct ReversedTrack<'t, I: TrackInterpolator> {
+    track: &'t mut Track<I>,
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    pub fn reversed(&mut self) -> ReversedTrack<'_, I> {
+        ReversedTrack {
+            track: self
+        }
+    }
+}
+
+impl<'t, I: TrackInterpolator> ReversedTrack<'t, I> {
+    pub fn key_start(&self) -> &Key<I> {
+        self.track.key_end()
+    }
+
+    pub fn key_end(&self) -> &Key<I> {
+        self.track.key_start()
+    }
+
+    /// Interpolates between the nodes around the `key`.
+    /// The node which is played first (the one with the greater key) is passed as the left one.
+    /// Windows of the interpolators with non-zero `WINDOW` are still in the key order.
+    pub fn interpolate(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        let track = &mut *self.track;
+        if *key <= track.key_start || *key > track.key_end {
+            return Err(Error::KeyNotInRange);
+        }
+
+        let cursor = track.cursor_at(key).unwrap();
+        let (lhs, rhs) = if track.cursor_key(cursor) == *key {
+            (cursor, track.cursor_prev(cursor).unwrap())
+        } else {
+            (track.cursor_next(cursor).unwrap(), cursor)
+        };
+
+        if I::WINDOW > 0 {
+            return track.interpolate_between(key, rhs, lhs, None);
+        }
+
+        let lhs_key = track.cursor_key(lhs);
+        let rhs_key = track.cursor_key(rhs);
+        let base = track.buf_index_base;
+
+        track.interpolator.interpolate(
+            key,
+            lhs_key,
+            &track.buf[lhs.index - base],
+            rhs_key,
+            &track.buf[rhs.index - base]
+        ).map_err(Error::Interpolation)
+    }
+
+    /// Drops the ranges which are already played, i.e. all the ranges after the one containing the `key`.
+    pub fn truncate_back(&mut self, key: &Key<I>) {
+        self.track.record(|| TrackMutation::ReversedTruncateBack(key.clone()));
+
+        let track = &mut *self.track;
+        if *key > track.key_end || track.ranges.len() < 2 {
+            return;
+        }
+
+        let last_range = track.ranges.len() - 1;
+        let range_index = if *key <= track.key_start {
+            0
+        } else {
+            let range_index = track.range_index(key);
+
+            if track.schedule.range_key(range_index) == *key {
+                range_index - 1
+            } else {
+                range_index
+            }
+        };
+
+        if range_index >= last_range {
+            return;
+        }
+
+        let (_, end) = track.ranges[range_index];
+        track.ranges.truncate_forward(range_index);
+        track.buf.truncate_forward(end - track.buf_index_base);
+
+        let key_end = track.schedule.range_key(range_index + 1);
+        let old_key_end = std::mem::replace(&mut track.key_end, key_end.clone());
+        track.next_step = track.schedule.step(range_index + 1).clone();
+
+        track.notify(|| TrackEvent::Canceled {
+            begin: key_end,
+            end: old_key_end
+        });
+    }
+
+    /// Cancels every node which key is less or equal to the `key`.
+    /// Since a track always starts with an aligned node,
+    /// the cancellation extends to the nearest aligned key after the `key`.
+    /// Canceled nodes are returned in the key order.
+    pub fn cancel_forward(&mut self, key: &Key<I>) -> buf::Truncated<'_, Node<I>> {
+        self.track.record(|| TrackMutation::ReversedCancelForward(key.clone()));
+
+        let track = &mut *self.track;
+        if *key < track.key_start || track.is_empty() {
+            return buf::Truncated::empty(&mut track.buf);
+        } else if *key >= track.key_end {
+            let key_end = track.key_end.clone();
+
+            return track.reset_track_helper(key_end);
+        }
+
+        let old_key_start = track.key_start.clone();
+        let ranges_count = track.ranges.len();
+        let removed_ranges = track.range_index(key) + 1;
+
+        let new_begin = if removed_ranges < ranges_count {
+            let (begin, _) = track.ranges[removed_ranges];
+            track.ranges.truncate_back(removed_ranges);
+            track.key_start = track.schedule.range_key(removed_ranges);
+            track.schedule.truncate_back(removed_ranges);
+
+            begin
+        } else {
+            let (_, end) = track.ranges[ranges_count - 1];
+            let last_node = &mut track.buf[end - track.buf_index_base];
+
+            if let TrackNode::NotAligned(_) = last_node {
+                let step = track.schedule.step(ranges_count - 1).clone();
+
+                *last_node = Data::<I>::clone(last_node).into();
+                track.schedule.reset(track.key_end.clone(), step.clone());
+                track.next_step = step;
+            } else {
+                track.schedule.truncate_back(removed_ranges);
+            }
+
+            track.ranges.clear();
+            track.key_start = track.key_end.clone();
+
+            end
+        };
+
+        let key_start = track.key_start.clone();
+        track.notify(|| TrackEvent::Truncated {
+            begin: old_key_start,
+            end: key_start
+        });
+
+        let canceled = new_begin - track.buf_index_base;
+        track.buf_index_base = new_begin;
+        track.buf.truncate_back(canceled)
+    }
+}
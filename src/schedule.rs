@@ -0,0 +1,166 @@
+use crate::{
+    TrackKey,
+    TrackKeyDistance,
+    ScalableDistance,
+};
+
+#[derive(Debug, Clone)]
+struct StepSegment<Key: TrackKey> {
+    range_index: usize,
+    key: Key,
+    step: Key::Distance,
+}
+
+/// Piecewise aligned step of a track.
+/// Every segment starts at an aligned key and lasts until the next segment.
+#[derive(Debug, Clone)]
+pub struct StepSchedule<Key: TrackKey> {
+    segments: Vec<StepSegment<Key>>,
+}
+
+impl<Key: TrackKey> StepSchedule<Key> {
+    pub fn new(key_start: Key, step: Key::Distance) -> Self {
+        Self {
+            segments: vec![
+                StepSegment {
+                    range_index: 0,
+                    key: key_start,
+                    step
+                }
+            ]
+        }
+    }
+
+    pub fn reset(&mut self, key_start: Key, step: Key::Distance) {
+        *self = Self::new(key_start, step);
+    }
+
+    pub fn range_index(&self, key: &Key) -> usize {
+        let segment = self.segment_by_key(key);
+
+        segment.range_index + segment.key.distance(key).div_floor(&segment.step)
+    }
+
+    pub fn range_key(&self, range_index: usize) -> Key {
+        let segment = self.segment_by_range(range_index);
+
+        segment.key.add_distance(
+            &segment.step.scale(range_index - segment.range_index)
+        )
+    }
+
+    pub fn step(&self, range_index: usize) -> &Key::Distance {
+        &self.segment_by_range(range_index).step
+    }
+
+    /// Replaces the step of the range `range_index` and of all the ranges after it
+    pub fn set_step_from(&mut self, range_index: usize, step: Key::Distance) {
+        let key = self.range_key(range_index);
+        let segments_left = self.segments.partition_point(
+            |segment| segment.range_index < range_index
+        );
+
+        self.segments.truncate(segments_left);
+        self.segments.push(StepSegment {
+            range_index,
+            key,
+            step
+        });
+    }
+
+    /// Makes the range `range_index` the first one
+    pub fn truncate_back(&mut self, range_index: usize) {
+        let key = self.range_key(range_index);
+        let first_segment = self.segments.partition_point(
+            |segment| segment.range_index <= range_index
+        ) - 1;
+
+        self.segments.drain(..first_segment);
+
+        let first = &mut self.segments[0];
+        first.key = key;
+        first.range_index = range_index;
+
+        for segment in self.segments.iter_mut() {
+            segment.range_index -= range_index;
+        }
+    }
+
+    pub fn shift(&mut self, distance: &Key::Distance) {
+        for segment in self.segments.iter_mut() {
+            segment.key = segment.key.add_distance(distance);
+        }
+    }
+
+    fn segment_by_key(&self, key: &Key) -> &StepSegment<Key> {
+        let index = self.segments.partition_point(|segment| segment.key <= *key);
+
+        &self.segments[index.saturating_sub(1)]
+    }
+
+    fn segment_by_range(&self, range_index: usize) -> &StepSegment<Key> {
+        let index = self.segments.partition_point(
+            |segment| segment.range_index <= range_index
+        );
+
+        &self.segments[index.saturating_sub(1)]
+    }
+}
+
+impl<Key: TrackKey> StepSchedule<Key>
+where
+    Key::Distance: ScalableDistance
+{
+    /// Scales every step by the `factor` keeping the first segment key.
+    /// Segment keys are derived from the scaled steps, so they stay aligned.
+    /// Returns `false` if some step is not positive after scaling.
+    pub fn scale(&mut self, factor: f64) -> bool {
+        for index in 0..self.segments.len() {
+            let step = self.segments[index].step.scale_f64(factor);
+
+            if index > 0 {
+                let prev = &self.segments[index - 1];
+                let ranges = self.segments[index].range_index - prev.range_index;
+                self.segments[index].key = prev.key.add_distance(&prev.step.scale(ranges));
+            }
+
+            self.segments[index].step = step;
+        }
+
+        self.segments
+            .iter()
+            .all(|segment| segment.step > Key::Distance::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schedule::StepSchedule;
+
+    #[test]
+    fn test_schedule() {
+        let mut schedule = StepSchedule::<i64>::new(0, 10);
+        schedule.set_step_from(2, 5);
+        schedule.set_step_from(4, 1);
+
+        assert_eq!(schedule.range_index(&0), 0);
+        assert_eq!(schedule.range_index(&19), 1);
+        assert_eq!(schedule.range_index(&20), 2);
+        assert_eq!(schedule.range_index(&29), 3);
+        assert_eq!(schedule.range_index(&31), 5);
+
+        assert_eq!(schedule.range_key(1), 10);
+        assert_eq!(schedule.range_key(3), 25);
+        assert_eq!(schedule.range_key(6), 32);
+        assert_eq!(*schedule.step(3), 5);
+
+        schedule.truncate_back(3);
+        assert_eq!(schedule.range_key(0), 25);
+        assert_eq!(schedule.range_index(&31), 2);
+        assert_eq!(*schedule.step(0), 5);
+        assert_eq!(*schedule.step(1), 1);
+
+        schedule.set_step_from(0, 2);
+        assert_eq!(schedule.range_key(3), 31);
+    }
+}
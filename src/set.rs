@@ -0,0 +1,225 @@
+use {
+    std::collections::BTreeMap,
+    crate::{
+        Track,
+        TrackInterpolator,
+        TrackNodeMut,
+        TrackState,
+        TrackKey,
+        Error,
+        Result,
+        InterpolationResult,
+        Key,
+        KeyDistance,
+        Data,
+        NotAlignedData,
+        Output,
+    }
+};
+
+/// Tracks of the several entities with the same aligned step and key origin.
+/// Every frame pushed by the `push_aligned_all` lands on the same key in each track.
+pub struct TrackSet<Id: Ord + Clone, I: TrackInterpolator + Clone> {
+    interpolator: I,
+    track_size: usize,
+    aligned_step: KeyDistance<I>,
+    tracks: BTreeMap<Id, Track<I>>,
+
+    /// Key of the next frame
+    next_key: Key<I>,
+}
+
+impl<Id: Ord + Clone, I: TrackInterpolator + Clone> TrackSet<Id, I> {
+    pub fn new(interpolator: I, track_size: usize, aligned_step: KeyDistance<I>) -> Self {
+        assert!(track_size > 1);
+
+        Self {
+            interpolator,
+            track_size,
+            aligned_step,
+            tracks: BTreeMap::new(),
+            next_key: Key::<I>::default(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    pub fn next_key(&self) -> &Key<I> {
+        &self.next_key
+    }
+
+    pub fn get(&self, id: &Id) -> Option<&Track<I>> {
+        self.tracks.get(id)
+    }
+
+    /// Changes the data of the entity node with the `key` in place, see the `Track::update`.
+    /// Returns `None` if there is no such entity or node.
+    pub fn update<F, R>(&mut self, id: &Id, key: &Key<I>, f: F) -> Option<R>
+    where
+        F: FnOnce(TrackNodeMut<'_, Data<I>, NotAlignedData<I>>) -> R
+    {
+        self.tracks.get_mut(id)?.update(key, f)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Id, &Track<I>)> {
+        self.tracks.iter()
+    }
+
+    pub fn remove(&mut self, id: &Id) -> Option<Track<I>> {
+        self.tracks.remove(id)
+    }
+
+    /// Pushes the frame at the `next_key`.
+    /// Entities missing in the `frame` hold their last data, new ones get a track starting at the frame.
+    /// If any track fails, every track is restored as it was before the frame.
+    /// Observers are notified only after the whole frame is pushed.
+    pub fn push_aligned_all<F>(&mut self, frame: F) -> Result<()>
+    where
+        F: IntoIterator<Item = (Id, Data<I>)>
+    {
+        let mut frame = frame.into_iter().collect::<BTreeMap<_, _>>();
+        let key = self.next_key.clone();
+
+        // New tracks are inserted only when the whole frame is pushed
+        let new_ids = frame.keys()
+            .filter(|id| !self.tracks.contains_key(id))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut new_tracks = vec![];
+        for id in new_ids {
+            let mut track = Track::new(self.interpolator.clone(), self.track_size, self.aligned_step.clone());
+            track.reset_track(key.clone());
+            track.push_aligned(frame.remove(&id).unwrap())?;
+
+            new_tracks.push((id, track));
+        }
+
+        let mut pushed = vec![];
+        let mut result = Ok(());
+
+        for (id, track) in self.tracks.iter_mut() {
+            let data = match frame.remove(id) {
+                Some(data) => data,
+                None => match track.node_end() {
+                    Some(node) => Data::<I>::clone(node),
+                    None => continue
+                }
+            };
+
+            pushed.push((id.clone(), TrackState::save(track)));
+            track.deferred_events = Some(vec![]);
+
+            if track.is_empty() {
+                track.reset_track(key.clone());
+            }
+
+            if let Err(err) = track.push_aligned(data) {
+                result = Err(err);
+                break;
+            }
+        }
+
+        for (id, state) in pushed {
+            let track = self.tracks.get_mut(&id).unwrap();
+            let events = track.deferred_events.take().unwrap_or_default();
+
+            match result {
+                Ok(()) => for event in events {
+                    track.notify(|| event);
+                },
+                Err(_) => state.restore(track)
+            }
+        }
+
+        result?;
+
+        self.tracks.extend(new_tracks);
+
+        self.next_key = key.add_distance(&self.aligned_step);
+
+        Ok(())
+    }
+
+    /// Outputs of the tracks which contain the `key`
+    pub fn interpolate_all(&mut self, key: &Key<I>) -> InterpolationResult<BTreeMap<Id, Output<I>>, I::Error> {
+        let mut outputs = BTreeMap::new();
+
+        for (id, track) in self.tracks.iter_mut() {
+            match track.interpolate(key) {
+                Ok(output) => {
+                    outputs.insert(id.clone(), output);
+                },
+                Err(Error::KeyNotInRange) => {},
+                Err(err) => return Err(err)
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    pub fn truncate_back(&mut self, key: &Key<I>) {
+        for track in self.tracks.values_mut() {
+            track.truncate_back(key);
+        }
+    }
+
+    /// Clears every track, the next frame is pushed at the `key_start`
+    pub fn reset(&mut self, key_start: Key<I>) {
+        for track in self.tracks.values_mut() {
+            track.reset_track(key_start.clone());
+        }
+
+        self.next_key = key_start;
+    }
+}
+
+impl<Id, I> TrackSet<Id, I>
+where
+    Id: Ord + Clone,
+    I: TrackInterpolator + Clone,
+    Data<I>: PartialEq
+{
+    /// Entities which data at some node from the `from` to the `to` (including)
+    /// differs from their data at the `from`.
+    /// Entities which first node is after the `from` are changed as well.
+    pub fn changed_between(&self, from: &Key<I>, to: &Key<I>) -> Vec<Id> {
+        self.tracks
+            .iter()
+            .filter(|(_, track)| Self::is_changed(track, from, to))
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    fn is_changed(track: &Track<I>, from: &Key<I>, to: &Key<I>) -> bool {
+        let mut cursor = match track.cursor_at(from) {
+            Some(cursor) => cursor,
+            None => return matches!(
+                track.cursor_first(),
+                Some(cursor) if track.cursor_key(cursor) <= *to
+            )
+        };
+
+        let data: &Data<I> = track.cursor_node(cursor);
+
+        while let Some(next) = track.cursor_next(cursor) {
+            if track.cursor_key(next) > *to {
+                break;
+            }
+
+            if **track.cursor_node(next) != *data {
+                return true;
+            }
+
+            cursor = next;
+        }
+
+        false
+    }
+}
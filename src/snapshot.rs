@@ -0,0 +1,51 @@
+use {
+    std::ops::Deref,
+    crate::{
+        Track,
+        TrackInterpolator,
+        InterpolationResult,
+        Key,
+        Output,
+    }
+};
+
+/// Immutable copy of a track which shares the nodes with it.
+/// It can be sent to another thread while the track keeps changing.
+pub struct TrackSnapshot<I: TrackInterpolator> {
+    track: Track<I>,
+}
+
+impl<I: TrackInterpolator> TrackSnapshot<I> {
+    pub fn interpolate(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        self.track.interpolate(key)
+    }
+}
+
+impl<I: TrackInterpolator> Deref for TrackSnapshot<I> {
+    type Target = Track<I>;
+
+    fn deref(&self) -> &Track<I> {
+        &self.track
+    }
+}
+
+impl<I: TrackInterpolator + Clone> Clone for TrackSnapshot<I> {
+    fn clone(&self) -> Self {
+        self.track.snapshot()
+    }
+}
+
+impl<I: TrackInterpolator + Clone> Track<I> {
+    /// Takes the snapshot of the track, the journal is not included.
+    /// Aggregate indices are shared with the track until it changes them.
+    pub fn snapshot(&self) -> TrackSnapshot<I> {
+        let mut track = self.fork();
+        track.journal = None;
+        track.fork_of = None;
+        track.aggregates = self.aggregates.clone();
+
+        TrackSnapshot {
+            track
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use {
+    std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex, MutexGuard, Condvar},
+        future::Future,
+        pin::Pin,
+        task::{Context, Poll, Waker},
+    },
+    crate::{
+        Track,
+        TrackInterpolator,
+        TrackSnapshot,
+        Inserted,
+        Error,
+        Result,
+        InterpolationResult,
+        Key,
+        Data,
+        NotAlignedData,
+        Node,
+        Output,
+    }
+};
+
+/// Count of the invalidations kept for the readers which are behind the writer
+const INVALIDATIONS_KEPT: usize = 64;
+
+/// Keys from the `begin` to the `end` (not including) which data was changed or removed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invalidated<Key> {
+    pub begin: Key,
+    pub end: Key,
+}
+
+impl<Key: Ord + Clone> Invalidated<Key> {
+    pub fn contains(&self, key: &Key) -> bool {
+        self.begin <= *key && *key < self.end
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            begin: std::cmp::min(self.begin, other.begin),
+            end: std::cmp::max(self.end, other.end)
+        }
+    }
+}
+
+struct Published<I: TrackInterpolator> {
+    snapshot: TrackSnapshot<I>,
+    invalidations: VecDeque<(usize, Invalidated<Key<I>>)>,
+
+    /// Latest version which invalidation is not kept anymore
+    forgotten_version: usize,
+
+    wakers: Vec<Waker>,
+    is_writer_dropped: bool,
+}
+
+struct Shared<I: TrackInterpolator> {
+    published: Mutex<Published<I>>,
+    changed: Condvar,
+}
+
+impl<I: TrackInterpolator> Shared<I> {
+    fn lock(&self) -> MutexGuard<'_, Published<I>> {
+        self.published.lock().unwrap()
+    }
+
+    fn notify(&self, published: &mut Published<I>) {
+        self.changed.notify_all();
+
+        for waker in published.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl<I: TrackInterpolator> Published<I> {
+    /// `Some` when the wait for the `key` is over
+    fn check_key(&self, key: &Key<I>) -> Option<Result<()>> {
+        if *key < *self.snapshot.key_start() {
+            Some(Err(Error::KeyNotInRange))
+        } else if *key < *self.snapshot.key_end() {
+            Some(Ok(()))
+        } else if self.is_writer_dropped {
+            Some(Err(Error::KeyNotInRange))
+        } else {
+            None
+        }
+    }
+}
+
+/// Track with a single writer and many readers in other threads.
+/// Every change is published as a snapshot, so the writer and the readers don't wait for each other.
+pub struct SyncTrack<I: TrackInterpolator> {
+    track: Track<I>,
+    shared: Arc<Shared<I>>,
+}
+
+impl<I: TrackInterpolator + Clone> SyncTrack<I> {
+    pub fn new(track: Track<I>) -> Self {
+        let published = Published {
+            snapshot: track.snapshot(),
+            invalidations: VecDeque::new(),
+            forgotten_version: 0,
+            wakers: vec![],
+            is_writer_dropped: false,
+        };
+
+        let shared = Shared {
+            published: Mutex::new(published),
+            changed: Condvar::new(),
+        };
+
+        Self {
+            track,
+            shared: Arc::new(shared)
+        }
+    }
+
+    pub fn track(&self) -> &Track<I> {
+        &self.track
+    }
+
+    pub fn reader(&self) -> SyncTrackReader<I> {
+        SyncTrackReader {
+            view: self.shared.lock().snapshot.clone(),
+            shared: self.shared.clone()
+        }
+    }
+
+    pub fn reset_track(&mut self, new_key_start: Key<I>) {
+        let invalidated = self.all_keys();
+        self.track.reset_track(new_key_start);
+        self.publish(invalidated);
+    }
+
+    pub fn push_aligned(&mut self, node: Data<I>) -> Result<()> {
+        self.track.push_aligned(node)?;
+        self.publish(None);
+
+        Ok(())
+    }
+
+    pub fn insert_not_aligned<Handler>(
+        &mut self,
+        key: Key<I>,
+        node: NotAlignedData<I>,
+        handler: Handler
+    ) -> Result<Inserted<Key<I>>>
+    where
+        Handler: FnMut(&mut Node<I>)
+    {
+        let inserted = self.track.insert_not_aligned(key.clone(), node, handler)?;
+
+        let begin = self.track.prev_node(&key).unwrap().0;
+        self.publish(Some(Invalidated {
+            begin,
+            end: inserted.old_key_end.clone()
+        }));
+
+        Ok(inserted)
+    }
+
+    pub fn cancel_forward(&mut self, key: &Key<I>) {
+        let old_key_end = self.track.key_end.clone();
+        self.track.cancel_forward(key);
+
+        self.publish(Some(Invalidated {
+            begin: self.track.key_end.clone(),
+            end: old_key_end
+        }));
+    }
+
+    pub fn truncate_back(&mut self, key: &Key<I>) {
+        let old_key_start = self.track.key_start.clone();
+        self.track.truncate_back(key);
+
+        self.publish(Some(Invalidated {
+            begin: old_key_start,
+            end: self.track.key_start.clone()
+        }));
+    }
+
+    fn all_keys(&self) -> Option<Invalidated<Key<I>>> {
+        Some(Invalidated {
+            begin: self.track.key_start.clone(),
+            end: self.track.key_end.clone()
+        })
+    }
+
+    fn publish(&mut self, invalidated: Option<Invalidated<Key<I>>>) {
+        let snapshot = self.track.snapshot();
+        let version = self.track.version();
+
+        let mut published = self.shared.lock();
+        published.snapshot = snapshot;
+
+        if let Some(invalidated) = invalidated.filter(|invalidated| invalidated.begin < invalidated.end) {
+            published.invalidations.push_back((version, invalidated));
+
+            if published.invalidations.len() > INVALIDATIONS_KEPT {
+                let (forgotten_version, _) = published.invalidations.pop_front().unwrap();
+                published.forgotten_version = forgotten_version;
+            }
+        }
+
+        self.shared.notify(&mut published);
+    }
+}
+
+impl<I: TrackInterpolator> Drop for SyncTrack<I> {
+    fn drop(&mut self) {
+        if let Ok(mut published) = self.shared.published.lock() {
+            published.is_writer_dropped = true;
+            self.shared.notify(&mut published);
+        }
+    }
+}
+
+/// Reader of a `SyncTrack` with its own consistent view of the track
+pub struct SyncTrackReader<I: TrackInterpolator> {
+    view: TrackSnapshot<I>,
+    shared: Arc<Shared<I>>,
+}
+
+impl<I: TrackInterpolator + Clone> SyncTrackReader<I> {
+    pub fn view(&self) -> &TrackSnapshot<I> {
+        &self.view
+    }
+
+    /// Moves the view to the latest published version.
+    /// Returns the keys changed since the previous view, if there are any besides the appended ones.
+    pub fn refresh(&mut self) -> Option<Invalidated<Key<I>>> {
+        let version = self.view.version();
+        let published = self.shared.lock();
+        if published.snapshot.version() == version {
+            return None;
+        }
+
+        let invalidated = if version < published.forgotten_version {
+            Some(Invalidated {
+                begin: self.view.key_start().clone(),
+                end: self.view.key_end().clone()
+            })
+        } else {
+            published.invalidations
+                .iter()
+                .filter(|(invalidated_version, _)| *invalidated_version > version)
+                .map(|(_, invalidated)| invalidated.clone())
+                .reduce(Invalidated::merge)
+        };
+
+        self.view = published.snapshot.clone();
+
+        invalidated
+    }
+
+    /// Interpolates the latest published version.
+    /// Returns the `Error::Invalidated` if the `key` was changed since the previous view.
+    /// The view is already refreshed then, so the call can be retried.
+    pub fn interpolate(&mut self, key: &Key<I>) -> InterpolationResult<Output<I>, I::Error> {
+        if let Some(invalidated) = self.refresh() {
+            if invalidated.contains(key) {
+                return Err(Error::Invalidated);
+            }
+        }
+
+        self.view.interpolate(key)
+    }
+
+    /// Blocks until the `key` is before the published `key_end`.
+    /// Fails if the `key` is behind the published `key_start` or the writer is dropped.
+    /// The view is not refreshed.
+    pub fn wait_for(&self, key: &Key<I>) -> Result<()> {
+        let mut published = self.shared.lock();
+
+        loop {
+            if let Some(result) = published.check_key(key) {
+                return result;
+            }
+
+            published = self.shared.changed.wait(published).unwrap();
+        }
+    }
+
+    /// Same as the `wait_for`, but doesn't block
+    pub fn wait_for_async(&self, key: Key<I>) -> WaitFor<'_, I> {
+        WaitFor {
+            shared: &self.shared,
+            key
+        }
+    }
+}
+
+/// Future of the `SyncTrackReader::wait_for_async`
+pub struct WaitFor<'r, I: TrackInterpolator> {
+    shared: &'r Shared<I>,
+    key: Key<I>,
+}
+
+impl<'r, I: TrackInterpolator> Future for WaitFor<'r, I> {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut published = self.shared.lock();
+
+        match published.check_key(&self.key) {
+            Some(result) => Poll::Ready(result),
+            None => {
+                if !published.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                    published.wakers.push(cx.waker().clone());
+                }
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<I: TrackInterpolator + Clone> Clone for SyncTrackReader<I> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view.clone(),
+            shared: self.shared.clone()
+        }
+    }
+}
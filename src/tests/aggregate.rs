@@ -0,0 +1,150 @@
+use crate::{
+    *,
+    tests::interpolator::*
+};
+
+pub struct MinMax;
+
+impl TrackAggregate for MinMax {
+    type Data = usize;
+    type Aggregate = (usize, usize);
+
+    fn identity(&self) -> (usize, usize) {
+        (usize::MAX, usize::MIN)
+    }
+
+    fn lift(&self, data: &usize) -> (usize, usize) {
+        (*data, *data)
+    }
+
+    fn combine(&self, lhs: &(usize, usize), rhs: &(usize, usize)) -> (usize, usize) {
+        (lhs.0.min(rhs.0), lhs.1.max(rhs.1))
+    }
+}
+
+struct Concat;
+
+impl TrackAggregate for Concat {
+    type Data = usize;
+    type Aggregate = Vec<usize>;
+
+    fn identity(&self) -> Vec<usize> {
+        vec![]
+    }
+
+    fn lift(&self, data: &usize) -> Vec<usize> {
+        vec![*data]
+    }
+
+    fn combine(&self, lhs: &Vec<usize>, rhs: &Vec<usize>) -> Vec<usize> {
+        lhs.iter().chain(rhs.iter()).copied().collect()
+    }
+}
+
+fn nodes_between(track: &Track<Interpolator>, from: i64, to: i64) -> Option<Vec<usize>> {
+    let nodes = track.nodes()
+        .filter(|(key, _)| from <= *key && *key <= to)
+        .map(|(_, node)| **node)
+        .collect::<Vec<_>>();
+
+    Some(nodes).filter(|nodes| !nodes.is_empty())
+}
+
+fn assert_aggregates(track: &Track<Interpolator>) {
+    let from = *track.key_start() - 10;
+    let to = *track.key_end() + 10;
+
+    for lhs in from..=to {
+        for rhs in lhs..=to {
+            assert_eq!(track.aggregate::<Concat>(&lhs, &rhs), nodes_between(track, lhs, rhs));
+        }
+    }
+}
+
+#[test]
+fn test_aggregate() -> Result<()> {
+    let mut track = Track::new(Interpolator, 6, 10);
+    assert_eq!(track.aggregate::<MinMax>(&0, &10), None);
+
+    track.enable_aggregate(MinMax);
+    assert_eq!(track.aggregate::<MinMax>(&0, &10), None);
+
+    track.push_aligned(5)?;
+    track.push_aligned(3)?;
+    track.push_aligned(8)?;
+    track.insert_not_aligned(15, DataNA(1), |_| {})?;
+    track.push_aligned(4)?;
+
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((1, 5)));
+    assert_eq!(track.aggregate::<MinMax>(&0, &14), Some((3, 5)));
+    assert_eq!(track.aggregate::<MinMax>(&16, &100), Some((4, 4)));
+    assert_eq!(track.aggregate::<MinMax>(&11, &14), None);
+    assert_eq!(track.aggregate::<MinMax>(&14, &11), None);
+    assert_eq!(track.aggregate::<Concat>(&0, &20), None);
+
+    track.cancel_forward(&15);
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((3, 5)));
+
+    track.enable_aggregate(Concat);
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((3, 5)));
+    assert_eq!(track.aggregate::<Concat>(&0, &20), Some(vec![5, 3]));
+
+    track.push_aligned(2)?;
+    assert_eq!(track.aggregate::<MinMax>(&0, &30), Some((2, 5)));
+    assert_eq!(track.aggregate::<Concat>(&0, &30), Some(vec![5, 3, 2]));
+
+    assert!(track.disable_aggregate::<MinMax>());
+    assert!(!track.disable_aggregate::<MinMax>());
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), None);
+    assert_eq!(track.aggregate::<Concat>(&0, &30), Some(vec![5, 3, 2]));
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_ring() -> Result<()> {
+    let mut track = Track::new(Interpolator, 4, 10);
+    track.enable_aggregate(Concat);
+
+    for node in 0..20 {
+        if track.push_aligned(node).is_err() {
+            track.truncate_back(&(*track.key_end() - 10));
+            track.push_aligned(node)?;
+        }
+
+        assert_aggregates(&track);
+    }
+
+    track.insert_not_aligned(*track.key_end() - 5, DataNA(100), |_| {})?;
+    assert_aggregates(&track);
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_grow() -> Result<()> {
+    let mut track = Track::new(Interpolator, 3, 10);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.enable_aggregate(Concat);
+
+    for node in 2..8 {
+        track.push_aligned(node)?;
+        track.insert_not_aligned(node as i64, DataNA(node), |_| {})?;
+        assert_aggregates(&track);
+    }
+
+    track.reset_track(50);
+    track.push_aligned(42)?;
+    assert_aggregates(&track);
+    assert_eq!(track.aggregate::<Concat>(&0, &100), Some(vec![42]));
+
+    Ok(())
+}
+
+#[test]
+fn test_aggregate_sync() {
+    fn assert_sync<T: Send + Sync>() {}
+
+    assert_sync::<Track<Interpolator>>();
+}
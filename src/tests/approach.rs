@@ -0,0 +1,53 @@
+use crate::{
+    *,
+    interpolators::*,
+    tests::{NotAligned},
+};
+
+type LinearTrack = Track<Linear<i64, f64, NotAligned>>;
+
+fn make_track(aligned_step: i64, key_start: i64, values: &[f64]) -> Result<LinearTrack> {
+    let mut track = Track::new(Linear::new(), 16, aligned_step);
+    track.reset_track(key_start);
+
+    for value in values {
+        track.push_aligned(*value)?;
+    }
+
+    Ok(track)
+}
+
+#[test]
+fn test_closest_approach() -> Result<()> {
+    let mut track = make_track(10, 0, &[0.0, 10.0, 20.0, 30.0])?;
+    let metric = |lhs: &f64, rhs: &f64| (lhs - rhs).abs();
+
+    let mut other = make_track(10, 0, &[30.0, 20.0, 10.0, 0.0])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, Some((15, 0.0)));
+
+    let mut other = make_track(5, 0, &[30.0, 25.0, 20.0, 15.0])?;
+    other.insert_not_aligned(12, NotAligned(18.0), |_| {})?;
+    other.push_aligned(15.0)?;
+    other.push_aligned(10.0)?;
+    other.push_aligned(5.0)?;
+    other.push_aligned(0.0)?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, Some((15, 0.0)));
+
+    let mut other = make_track(10, 0, &[50.0, 40.0, 30.0])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, Some((20, 10.0)));
+
+    let mut other = make_track(10, 25, &[40.0, 35.0])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, Some((30, 7.5)));
+
+    let mut other = make_track(10, 40, &[40.0, 35.0])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, None);
+
+    let mut other = make_track(10, 0, &[])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, None);
+
+    let mut other = make_track(10, 10, &[10.0])?;
+    assert_eq!(track.closest_approach(&mut other, &1, metric)?, None);
+    assert_eq!(other.closest_approach(&mut track, &1, metric)?, None);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use crate::{
+    *,
+    interpolators::*,
+    tests::{NotAligned, assert_near},
+};
+
+#[test]
+fn test_derivative() -> Result<()> {
+    let mut track = Track::new(Linear::<i64, f64, NotAligned>::new(), 8, 10);
+    assert_eq!(track.derivative_at(&0).unwrap_err(), Error::KeyNotInRange);
+
+    track.push_aligned(0.0)?;
+    assert_eq!(track.derivative_at(&0).unwrap_err(), Error::KeyNotInRange);
+
+    track.push_aligned(10.0)?;
+    track.push_aligned(30.0)?;
+    track.insert_not_aligned(15, NotAligned(10.0), |_| {})?;
+    track.push_aligned(30.0)?;
+
+    assert_near(track.derivative_at(&0)?, 1.0);
+    assert_near(track.derivative_at(&12)?, 0.0);
+    assert_near(track.derivative_at(&15)?, 4.0);
+    assert_near(track.derivative_at(&20)?, 4.0);
+    assert_eq!(track.derivative_at(&21).unwrap_err(), Error::KeyNotInRange);
+
+    Ok(())
+}
+
+#[test]
+fn test_integrate() -> Result<()> {
+    let mut track = Track::new(Linear::<i64, f64, NotAligned>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(10.0)?;
+    track.push_aligned(30.0)?;
+    track.insert_not_aligned(15, NotAligned(10.0), |_| {})?;
+    track.push_aligned(30.0)?;
+
+    assert_near(track.integrate(&0, &10)?, 50.0);
+    assert_near(track.integrate(&5, &5)?, 0.0);
+    assert_near(track.integrate(&10, &20)?, 50.0 + 100.0);
+    assert_near(track.integrate(&0, &20)?, 50.0 + 150.0);
+    assert_near(track.integrate(&5, &16)?, 37.5 + 50.0 + 12.0);
+    assert_near(track.integrate(&20, &20)?, 0.0);
+
+    assert_eq!(track.integrate(&-1, &10).unwrap_err(), Error::KeyNotInRange);
+    assert_eq!(track.integrate(&0, &21).unwrap_err(), Error::KeyNotInRange);
+    assert_eq!(track.integrate(&10, &0).unwrap_err(), Error::KeyNotInRange);
+
+    let mut track = Track::new(Step::<i64, f64, NotAligned>::new(), 8, 10);
+    track.push_aligned(1.0)?;
+    track.push_aligned(2.0)?;
+    track.push_aligned(4.0)?;
+
+    assert_near(track.integrate(&5, &20)?, 5.0 + 20.0);
+    assert_near(track.derivative_at(&5)?, 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_hermite_calculus() -> Result<()> {
+    let mut track = Track::new(Hermite::<i64, f64, NotAligned<HermiteNode<f64>>>::new(), 8, 10);
+
+    for x in 0..4 {
+        let x = (x * 10) as f64;
+        track.push_aligned(HermiteNode { value: x * x, tangent: 2.0 * x })?;
+    }
+
+    assert_near(track.derivative_at(&15)?, 30.0);
+    assert_near(track.derivative_at(&30)?, 60.0);
+    assert_near(track.integrate(&0, &30)?, 9000.0);
+    assert_near(track.integrate(&5, &15)?, (3375.0 - 125.0) / 3.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_catmull_rom_calculus() -> Result<()> {
+    let mut track = Track::new(CatmullRom::<i64, f64, NotAligned>::new(), 8, 10);
+
+    // Inner tangents of x^2 are exact, so the inner segment is exact too
+    for x in 0..4 {
+        let x = (x * 10) as f64;
+        track.push_aligned(x * x)?;
+    }
+
+    assert_near(track.derivative_at(&15)?, 30.0);
+    assert_near(track.integrate(&10, &20)?, 7000.0 / 3.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_looped_calculus() -> Result<()> {
+    let mut track = Track::new(Linear::<i64, f64, NotAligned>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(10.0)?;
+    track.set_loop_length(Some(20));
+
+    assert_near(track.derivative_at(&5)?, 1.0);
+    assert_near(track.derivative_at(&15)?, -1.0);
+    assert_near(track.derivative_at(&25)?, 1.0);
+    assert_near(track.derivative_at(&-5)?, -1.0);
+
+    assert_near(track.integrate(&0, &20)?, 100.0);
+    assert_near(track.integrate(&12, &15)?, 19.5);
+    assert_near(track.integrate(&5, &45)?, 200.0);
+    assert_near(track.integrate(&-20, &0)?, 100.0);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::{
+    *,
+    interpolators::*,
+    tests::NotAligned,
+};
+
+#[test]
+fn test_find_first() -> Result<()> {
+    let mut track = Track::new(Linear::<i64, f64, NotAligned>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(10.0)?;
+    track.push_aligned(30.0)?;
+    track.insert_not_aligned(15, NotAligned(10.0), |_| {})?;
+    track.push_aligned(40.0)?;
+    track.push_aligned(0.0)?;
+
+    assert_eq!(track.find_first(&0, &29, &1, |out| *out >= 20.0)?, Some(17));
+    assert_eq!(track.find_first(&0, &29, &0, |out| *out >= 20.0)?, Some(17));
+    assert_eq!(track.find_first(&0, &29, &4, |out| *out >= 20.0)?, Some(18));
+    assert_eq!(track.find_first(&0, &29, &1, |out| *out >= 10.0)?, Some(10));
+    assert_eq!(track.find_first(&3, &29, &1, |out| *out >= 0.0)?, Some(3));
+    assert_eq!(track.find_first(&20, &29, &1, |out| *out < 20.0)?, Some(26));
+    assert_eq!(track.find_first(&0, &29, &1, |out| *out > 40.0)?, None);
+    assert_eq!(track.find_first(&0, &12, &1, |out| *out >= 20.0)?, None);
+
+    assert_eq!(track.find_first(&20, &30, &1, |out| *out <= 0.0)?, Some(30));
+    assert_eq!(track.find_first(&30, &30, &1, |out| *out <= 0.0)?, Some(30));
+    assert_eq!(track.find_first(&0, &30, &1, |_| false)?, None);
+
+    assert_eq!(track.find_first(&0, &31, &1, |_| unreachable!()).unwrap_err(), Error::KeyNotInRange);
+    assert_eq!(track.find_first(&-1, &30, &1, |_| unreachable!()).unwrap_err(), Error::KeyNotInRange);
+    assert_eq!(track.find_first(&12, &10, &1, |_| true).unwrap_err(), Error::KeyNotInRange);
+
+    Ok(())
+}
+
+#[test]
+fn test_find_first_looped() -> Result<()> {
+    let mut track = Track::new(Linear::<i64, f64, NotAligned>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(10.0)?;
+    track.push_aligned(0.0)?;
+    track.set_loop_length(Some(30));
+
+    // The crossing is in the next loop
+    assert_eq!(track.find_first(&15, &100, &0, |out| *out >= 9.0)?, Some(39));
+    assert_eq!(track.find_first(&15, &35, &0, |out| *out >= 9.0)?, None);
+    assert_eq!(track.find_first(&-25, &0, &0, |out| *out >= 9.0)?, Some(-21));
+    assert_eq!(track.find_first(&22, &100, &0, |out| *out > 0.0)?, Some(31));
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use {
+    std::{
+        sync::mpsc,
+        thread,
+    },
+    crate::{
+        *,
+        tests::{
+            interpolator::*,
+            nodes,
+        }
+    }
+};
+
+type Diff = TrackDiff<i64, usize, DataNA>;
+
+#[test]
+fn test_diff() -> Result<()> {
+    let mut track = Track::new(Interpolator, 4, 10);
+    track.push_aligned(0)?;
+    assert!(track.diff_since(0).is_none());
+
+    track.enable_journal();
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    assert_eq!(track.version(), 3);
+    assert!(track.diff_since(0).is_none());
+    assert!(track.diff_since(4).is_none());
+
+    let diff = track.diff_since(1).unwrap();
+    assert_eq!(diff.from_version(), 1);
+    assert_eq!(diff.to_version(), 3);
+    assert_eq!(diff.mutations(), &[TrackMutation::PushAligned(1), TrackMutation::PushAligned(2)]);
+    assert!(track.diff_since(3).unwrap().is_empty());
+
+    let mut mirror = Track::new(Interpolator, 4, 10);
+    assert_eq!(mirror.apply_diff(diff.clone()), Err(Error::VersionMismatch));
+
+    mirror.push_aligned(0)?;
+    mirror.apply_diff(diff)?;
+    assert_eq!(nodes(&mirror), nodes(&track));
+    assert_eq!(mirror.version(), 3);
+
+    track.trim_journal(2);
+    assert!(track.diff_since(1).is_none());
+    assert_eq!(track.diff_since(2).unwrap().mutations().len(), 1);
+
+    track.disable_journal();
+    track.scale_time(2.0)?;
+    assert_eq!(track.version(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_diff_replication() -> Result<()> {
+    let (diff_sender, diff_receiver) = mpsc::channel::<Diff>();
+    let (state_sender, state_receiver) = mpsc::channel();
+
+    let mirror = thread::spawn(move || {
+        let mut mirror = Track::new(Interpolator, 4, 10);
+
+        for diff in diff_receiver {
+            mirror.apply_diff(diff).unwrap();
+            state_sender.send((nodes(&mirror), *mirror.key_start(), *mirror.key_end())).unwrap();
+        }
+    });
+
+    let mut track = Track::new(Interpolator, 4, 10);
+    track.enable_journal();
+
+    let sync = move |track: &mut Track<Interpolator>| {
+        let version = track.version() - track.journal().unwrap().len();
+        let diff = track.diff_since(version).unwrap();
+        track.trim_journal(diff.to_version());
+        diff_sender.send(diff).unwrap();
+
+        let expected = (nodes(track), *track.key_start(), *track.key_end());
+        assert_eq!(state_receiver.recv().unwrap(), expected);
+    };
+
+    track.reset_track(100);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    sync(&mut track);
+
+    track.push_aligned(2)?;
+    track.insert_not_aligned(115, DataNA(3), |_| {})?;
+    sync(&mut track);
+
+    track.truncate_back(&110);
+    track.push_aligned(4)?;
+    track.push_aligned(5)?;
+    sync(&mut track);
+
+    track.cancel_forward(&130);
+    sync(&mut track);
+
+    track.update(&120, |node| if let TrackNodeMut::Aligned(node) = node { *node = 40 });
+    track.set_aligned_step_from(*track.key_end(), 5)?;
+    track.push_aligned(7)?;
+    sync(&mut track);
+
+    track.shift(&-100);
+    track.reversed().truncate_back(&22);
+    sync(&mut track);
+
+    track.scale_time(2.0)?;
+    sync(&mut track);
+
+    track.reset_track(0);
+    track.push_aligned(6)?;
+    sync(&mut track);
+
+    drop(sync);
+    mirror.join().unwrap();
+
+    Ok(())
+}
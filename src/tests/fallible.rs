@@ -0,0 +1,59 @@
+use crate::{
+    *,
+    tests::interpolator::DataNA,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Discontinuity(i64);
+
+/// Fails when the nodes around the key differ by more than 10
+#[derive(Clone)]
+struct ContinuousInterpolator;
+
+impl TrackInterpolator for ContinuousInterpolator {
+    type Key = i64;
+    type Data = usize;
+    type NotAlignedData = DataNA;
+    type Output = usize;
+    type Error = Discontinuity;
+
+    fn interpolate(
+        &mut self, 
+        key: &i64, 
+        _: i64,
+        lhs: &TrackNode<i64, usize, DataNA>, 
+        _: i64,
+        rhs: &TrackNode<i64, usize, DataNA>
+    ) -> std::result::Result<usize, Discontinuity> { 
+        let (lhs, rhs): (usize, usize) = (**lhs, **rhs);
+
+        if lhs.abs_diff(rhs) > 10 {
+            Err(Discontinuity(*key))
+        } else {
+            Ok(lhs)
+        }
+    }
+}
+
+#[test]
+fn test_interpolation_error() -> InterpolationResult<(), Discontinuity> {
+    let mut track = Track::new(ContinuousInterpolator, 8, 10);
+
+    track.push_aligned(0).map_err(Error::widen)?;
+    track.push_aligned(10).map_err(Error::widen)?;
+    track.push_aligned(100).map_err(Error::widen)?;
+
+    assert_eq!(track.interpolate(&5)?, 0);
+    assert_eq!(track.interpolate(&15), Err(Error::Interpolation(Discontinuity(15))));
+    assert_eq!(track.interpolate(&30), Err(Error::KeyNotInRange));
+
+    track.set_loop_length(Some(30));
+    assert_eq!(track.interpolate(&25), Err(Error::Interpolation(Discontinuity(25))));
+    assert_eq!(track.interpolate(&-5), Err(Error::Interpolation(Discontinuity(25))));
+
+    track.set_loop_length(None);
+    assert_eq!(track.reversed().interpolate(&10)?, 10);
+    assert_eq!(track.reversed().interpolate(&12), Err(Error::Interpolation(Discontinuity(12))));
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        aggregate::MinMax,
+        nodes,
+    }
+};
+
+#[test]
+fn test_fork() -> Result<()> {
+    let mut track = Track::new(Interpolator, 64, 10);
+    for node in 0..40 {
+        track.push_aligned(node)?;
+    }
+
+    let mut fork = track.fork();
+    fork.insert_not_aligned(385, DataNA(100), |_| {})?;
+    fork.push_aligned(101)?;
+
+    let mut other_fork = track.fork();
+    other_fork.cancel_forward(&200);
+    other_fork.push_aligned(200)?;
+
+    assert_eq!(nodes(&track).len(), 40);
+    assert_eq!(track.key_end(), &390);
+    assert_eq!(fork.key_end(), &390);
+    assert_eq!(nodes(&fork)[38..], [(380, 38), (385, 100), (390, 101)]);
+    assert_eq!(nodes(&other_fork)[19..], [(190, 19), (200, 200)]);
+
+    let mut same_version = Track::new(Interpolator, 64, 10);
+    for node in 0..40 {
+        same_version.push_aligned(node)?;
+    }
+
+    assert_eq!(same_version.version(), track.version());
+    assert_eq!(same_version.commit(track.fork()), Err(Error::VersionMismatch));
+    assert_eq!(track.commit(track.fork().fork()), Err(Error::VersionMismatch));
+
+    track.push_aligned(40)?;
+    assert_eq!(track.commit(fork), Err(Error::VersionMismatch));
+    assert_eq!(nodes(&track).len(), 41);
+    assert_eq!(nodes(&other_fork).len(), 21);
+
+    Ok(())
+}
+
+#[test]
+fn test_fork_commit() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.enable_aggregate(MinMax);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.enable_journal();
+    track.push_aligned(2)?;
+
+    let mut fork = track.fork();
+    fork.insert_not_aligned(15, DataNA(30), |_| {})?;
+    fork.push_aligned(4)?;
+
+    track.commit(fork)?;
+    assert_eq!(nodes(&track), vec![(0, 0), (10, 1), (15, 30), (20, 4)]);
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((0, 30)));
+    assert_eq!(track.version(), 5);
+    assert_eq!(track.journal().unwrap(), &[
+        TrackMutation::PushAligned(2),
+        TrackMutation::InsertNotAligned(15, DataNA(30)),
+        TrackMutation::PushAligned(4),
+    ]);
+
+    let mut fork = track.fork();
+    fork.push_aligned(5)?;
+    fork.take_journal();
+
+    track.commit(fork)?;
+    assert_eq!(track.version(), 6);
+    assert_eq!(track.journal().unwrap(), &[]);
+    assert!(track.diff_since(5).is_none());
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use {
-    std::ops::Deref,
+    std::{
+        ops::Deref,
+        convert::Infallible,
+    },
     crate::*
 };
 
@@ -11,6 +14,7 @@ type Data = usize;
 
 type Node = node::TrackNode<Key, Data, DataNA>;
 
+#[derive(Clone)]
 pub struct Interpolator;
 
 impl TrackInterpolator for Interpolator {
@@ -18,6 +22,7 @@ impl TrackInterpolator for Interpolator {
     type Data = Data;
     type NotAlignedData = DataNA;
     type Output = Output;
+    type Error = Infallible;
     
     fn interpolate(
         &mut self, 
@@ -26,14 +31,14 @@ impl TrackInterpolator for Interpolator {
         begin_node: &Node, 
         end_key: Self::Key,
         end_node: &Node
-    ) -> Self::Output { 
-        Self::Output {
+    ) -> std::result::Result<Self::Output, Infallible> { 
+        Ok(Self::Output {
             requested_key: requested_key.clone(),
             begin_key,
             begin_node: begin_node.clone(),
             end_key,
             end_node: end_node.clone()
-        }
+        })
     }
 }
 
@@ -63,6 +68,18 @@ impl TrackKeyDistance for Distance {
     }
 }
 
+impl MeasurableDistance for Distance {
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl ScalableDistance for Distance {
+    fn scale_f64(&self, factor: f64) -> Self {
+        (*self as f64 * factor).round() as Distance
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DataNA(pub Data);
 
@@ -74,6 +91,12 @@ impl Deref for DataNA {
     }
 }
 
+impl From<Data> for DataNA {
+    fn from(data: Data) -> Self {
+        Self(data)
+    }
+}
+
 #[derive(Debug)]
 pub struct Output {
     pub requested_key: Key,
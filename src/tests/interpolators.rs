@@ -0,0 +1,116 @@
+use crate::{
+    *,
+    interpolators::*,
+    tests::{NotAligned, assert_near},
+};
+
+type Key = i64;
+
+#[test]
+fn test_step() -> Result<()> {
+    let mut track = Track::new(Step::<Key, f64, NotAligned<f64>>::new(), 8, 10);
+
+    track.push_aligned(1.0)?;
+    track.push_aligned(2.0)?;
+    track.push_aligned(4.0)?;
+    track.insert_not_aligned(15, NotAligned(3.0), |_| {})?;
+
+    assert_near(track.interpolate(&0)?, 1.0);
+    assert_near(track.interpolate(&9)?, 1.0);
+    assert_near(track.interpolate(&10)?, 2.0);
+    assert_near(track.interpolate(&14)?, 2.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_linear() -> Result<()> {
+    let mut track = Track::new(Linear::<Key, f64, NotAligned<f64>>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(10.0)?;
+    track.push_aligned(30.0)?;
+    track.insert_not_aligned(15, NotAligned(10.0), |_| {})?;
+    track.push_aligned(30.0)?;
+
+    assert_near(track.interpolate(&0)?, 0.0);
+    assert_near(track.interpolate(&4)?, 4.0);
+    assert_near(track.interpolate(&10)?, 10.0);
+    assert_near(track.interpolate(&12)?, 10.0);
+    assert_near(track.interpolate(&16)?, 14.0);
+
+    assert_near(1.0f64.lerp(&3.0, 0.25), 1.5);
+    assert_near(key_fraction(&15, &10, &20), 0.5);
+    assert_near(key_fraction(&10, &10, &10), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_hermite() -> Result<()> {
+    let mut track = Track::new(Hermite::<Key, f64, NotAligned<HermiteNode<f64>>>::new(), 8, 10);
+
+    // x^2 sampled with exact derivatives is reproduced exactly by a cubic
+    for x in 0..4 {
+        let x = (x * 10) as f64;
+        track.push_aligned(HermiteNode { value: x * x, tangent: 2.0 * x })?;
+    }
+
+    for key in 0..30 {
+        let x = key as f64;
+        assert_near(track.interpolate(&key)?, x * x);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_catmull_rom() -> Result<()> {
+    let mut track = Track::new(CatmullRom::<Key, f64, NotAligned<f64>>::new(), 8, 10);
+
+    track.push_aligned(0.0)?;
+    track.push_aligned(20.0)?;
+    track.insert_not_aligned(4, NotAligned(8.0), |_| {})?;
+    track.push_aligned(20.0)?;
+    track.push_aligned(40.0)?;
+    track.push_aligned(60.0)?;
+
+    // Linear functions are reproduced even with non-uniform knots
+    for key in 0..30 {
+        assert_near(track.interpolate(&key)?, 2.0 * key as f64);
+    }
+
+    let mut track = Track::new(CatmullRom::<Key, f64, NotAligned<f64>>::new(), 8, 10);
+    for x in 0..5 {
+        let x = (x * 10) as f64;
+        track.push_aligned(x * x)?;
+    }
+
+    // Central differences are exact for x^2 inside the track
+    for key in 10..30 {
+        let x = key as f64;
+        assert_near(track.interpolate(&key)?, x * x);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_catmull_rom_looped() -> Result<()> {
+    let mut track = Track::new(CatmullRom::<Key, f64, NotAligned<f64>>::new(), 8, 10);
+    for value in [0.0, 10.0, 0.0, -10.0].iter() {
+        track.push_aligned(*value)?;
+    }
+
+    track.set_loop_length(Some(40));
+
+    // Neighbours of the first and the last segments are taken across the loop seam
+    assert_near(track.interpolate(&5)?, 6.25);
+    assert_near(track.interpolate(&15)?, 6.25);
+    assert_near(track.interpolate(&25)?, -6.25);
+    assert_near(track.interpolate(&35)?, -6.25);
+    assert_near(track.interpolate(&45)?, 6.25);
+    assert_near(track.interpolate(&-5)?, -6.25);
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        nodes,
+    }
+};
+
+#[test]
+fn test_journal_replay() -> Result<()> {
+    let mut track = Track::new(Interpolator, 4, 10);
+    track.push_aligned(100)?;
+    assert_eq!(track.journal(), None);
+
+    track.enable_journal();
+    track.reset_track(50);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    track.insert_not_aligned(65, DataNA(3), |_| {})?;
+    track.push_aligned(4)?;
+    assert_eq!(track.push_aligned(5), Err(Error::Overflow));
+    track.truncate_back(&60);
+    track.push_aligned(5)?;
+    track.cancel_forward(&80);
+    track.push_aligned(6)?;
+    assert_eq!(track.transaction().push_aligned(7).push_aligned(8).commit().unwrap_err(), Error::Overflow);
+
+    let journal = track.journal().unwrap().to_vec();
+    assert_eq!(journal, vec![
+        TrackMutation::ResetTrack(50),
+        TrackMutation::PushAligned(0),
+        TrackMutation::PushAligned(1),
+        TrackMutation::PushAligned(2),
+        TrackMutation::InsertNotAligned(65, DataNA(3)),
+        TrackMutation::PushAligned(4),
+        TrackMutation::TruncateBack(60),
+        TrackMutation::PushAligned(5),
+        TrackMutation::CancelForward(80),
+        TrackMutation::PushAligned(6),
+    ]);
+
+    let mut replayed = Track::new(Interpolator, 4, 10);
+    replayed.replay(journal)?;
+
+    assert_eq!(nodes(&replayed), nodes(&track));
+    assert_eq!(replayed.key_start(), track.key_start());
+    assert_eq!(replayed.key_end(), track.key_end());
+
+    assert_eq!(track.take_journal().len(), 10);
+    assert_eq!(track.journal(), Some(&[][..]));
+
+    track.disable_journal();
+    track.cancel_forward(&80);
+    assert_eq!(track.journal(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_journal_all_mutators() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.enable_journal();
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    track.insert_not_aligned(15, DataNA(3), |_| {})?;
+    track.push_aligned(4)?;
+    track.set_aligned_step_from(20, 5)?;
+    track.push_aligned(5)?;
+    track.push_aligned(6)?;
+    track.update(&10, |node| if let TrackNodeMut::Aligned(node) = node { *node = 11 });
+    track.update(&15, |node| if let TrackNodeMut::NotAligned(node) = node { *node = DataNA(13) });
+    track.shift(&100);
+    track.set_loop_length(Some(40));
+    track.reversed().truncate_back(&127);
+    track.reversed().cancel_forward(&105);
+    track.scale_time(2.0)?;
+
+    let journal = track.journal().unwrap().to_vec();
+    assert_eq!(journal[5..], [
+        TrackMutation::SetAlignedStepFrom(20, 5),
+        TrackMutation::PushAligned(5),
+        TrackMutation::PushAligned(6),
+        TrackMutation::Update(10, 11),
+        TrackMutation::UpdateNotAligned(15, DataNA(13)),
+        TrackMutation::Shift(100),
+        TrackMutation::SetLoopLength(Some(40)),
+        TrackMutation::ReversedTruncateBack(127),
+        TrackMutation::ReversedCancelForward(105),
+        TrackMutation::ScaleTime(2.0),
+    ]);
+
+    let mut replayed = Track::new(Interpolator, 8, 10);
+    replayed.replay(journal)?;
+
+    assert_eq!(nodes(&replayed), nodes(&track));
+    assert_eq!(replayed.key_start(), track.key_start());
+    assert_eq!(replayed.key_end(), track.key_end());
+    assert_eq!(replayed.loop_length(), track.loop_length());
+    assert_eq!(replayed.aligned_step, track.aligned_step);
+
+    replayed.push_aligned(7)?;
+    track.push_aligned(7)?;
+    assert_eq!(nodes(&replayed), nodes(&track));
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mutation_serde() {
+    fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+
+    assert_serde::<TrackMutation<i64, usize, usize>>();
+}
@@ -0,0 +1,54 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        aggregate::MinMax,
+    }
+};
+
+#[test]
+fn test_sample_lod() -> Result<()> {
+    let mut track = Track::new(Interpolator, 32, 10);
+    assert!(track.sample_lod::<MinMax>(&0, &100, 4).is_empty());
+
+    track.enable_aggregate(MinMax);
+    assert!(track.sample_lod::<MinMax>(&0, &100, 4).is_empty());
+
+    track.push_aligned(7)?;
+    assert_eq!(track.sample_lod::<MinMax>(&0, &100, 4), vec![(0, (7, 7))]);
+
+    track.reset_track(0);
+    for node in 0..=16 {
+        track.push_aligned(node)?;
+    }
+
+    assert_eq!(
+        track.sample_lod::<MinMax>(&0, &160, 4),
+        vec![(0, (0, 3)), (40, (4, 7)), (80, (8, 11)), (120, (12, 16))]
+    );
+
+    let points = track.sample_lod::<MinMax>(&-100, &1000, 100);
+    assert_eq!(points.len(), 16);
+    assert_eq!(points[0], (0, (0, 0)));
+    assert_eq!(points[15], (150, (15, 16)));
+
+    assert_eq!(track.sample_lod::<MinMax>(&35, &75, 2), vec![(0, (0, 3)), (40, (4, 7))]);
+    assert_eq!(track.sample_lod::<MinMax>(&35, &75, 3), vec![(20, (2, 3)), (40, (4, 5)), (60, (6, 7))]);
+    assert_eq!(track.sample_lod::<MinMax>(&100, &100, 3), vec![(100, (10, 10))]);
+    assert_eq!(track.sample_lod::<MinMax>(&0, &160, 1), vec![(0, (0, 16))]);
+
+    assert!(track.sample_lod::<MinMax>(&0, &160, 0).is_empty());
+    assert!(track.sample_lod::<MinMax>(&161, &200, 4).is_empty());
+    assert!(track.sample_lod::<MinMax>(&50, &40, 4).is_empty());
+
+    track.insert_not_aligned(155, DataNA(100), |_| {})?;
+    assert_eq!(track.sample_lod::<MinMax>(&150, &160, 4), vec![(150, (15, 100))]);
+
+    track.truncate_back(&80);
+    assert_eq!(
+        track.sample_lod::<MinMax>(&0, &160, 2),
+        vec![(80, (8, 11)), (120, (12, 100))]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::{
+    *,
+    tests::interpolator::*
+};
+
+fn entry(entry: Option<(i64, &TrackNode<i64, usize, DataNA>)>) -> Option<(i64, usize)> {
+    entry.map(|(key, node)| (key, **node))
+}
+
+#[test]
+fn test_node_lookup() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    assert_eq!(entry(track.node_at(&0)), None);
+    assert_eq!(entry(track.next_node(&0)), None);
+    assert_eq!(entry(track.nearest_node(&0)), None);
+
+    track.reset_track(100);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    track.insert_not_aligned(113, DataNA(3), |_| {})?;
+    track.push_aligned(4)?;
+
+    assert_eq!(entry(track.node_at(&100)), Some((100, 0)));
+    assert_eq!(entry(track.node_at(&113)), Some((113, 3)));
+    assert_eq!(entry(track.node_at(&120)), Some((120, 4)));
+    assert_eq!(entry(track.node_at(&105)), None);
+    assert_eq!(entry(track.node_at(&99)), None);
+    assert_eq!(entry(track.node_at(&130)), None);
+
+    assert_eq!(entry(track.next_node(&90)), Some((100, 0)));
+    assert_eq!(entry(track.next_node(&100)), Some((110, 1)));
+    assert_eq!(entry(track.next_node(&110)), Some((113, 3)));
+    assert_eq!(entry(track.next_node(&115)), Some((120, 4)));
+    assert_eq!(entry(track.next_node(&120)), None);
+
+    assert_eq!(entry(track.prev_node(&100)), None);
+    assert_eq!(entry(track.prev_node(&101)), Some((100, 0)));
+    assert_eq!(entry(track.prev_node(&113)), Some((110, 1)));
+    assert_eq!(entry(track.prev_node(&120)), Some((113, 3)));
+    assert_eq!(entry(track.prev_node(&200)), Some((120, 4)));
+
+    assert_eq!(entry(track.nearest_node(&0)), Some((100, 0)));
+    assert_eq!(entry(track.nearest_node(&104)), Some((100, 0)));
+    assert_eq!(entry(track.nearest_node(&105)), Some((100, 0)));
+    assert_eq!(entry(track.nearest_node(&106)), Some((110, 1)));
+    assert_eq!(entry(track.nearest_node(&112)), Some((113, 3)));
+    assert_eq!(entry(track.nearest_node(&117)), Some((120, 4)));
+    assert_eq!(entry(track.nearest_node(&200)), Some((120, 4)));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::{
+    *,
+    tests::interpolator::*
+};
+
+#[test]
+fn test_looping() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.reset_track(100);
+
+    track.set_loop_length(Some(40));
+    assert_eq!(track.interpolate(&100).unwrap_err(), Error::KeyNotInRange);
+
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.push_aligned(30)?;
+    assert_eq!(track.loop_length(), Some(&40));
+
+    let out = track.interpolate(&145)?;
+    assert_eq!(out.requested_key, 105);
+    assert_eq!(out.begin_key, 100);
+    assert_eq!(*out.begin_node, 0);
+    assert_eq!(out.end_key, 110);
+    assert_eq!(*out.end_node, 10);
+
+    let out = track.interpolate(&135)?;
+    assert_eq!(out.requested_key, 135);
+    assert_eq!(out.begin_key, 130);
+    assert_eq!(*out.begin_node, 30);
+    assert_eq!(out.end_key, 140);
+    assert_eq!(*out.end_node, 0);
+
+    let out = track.interpolate(&95)?;
+    assert_eq!(out.requested_key, 135);
+    assert_eq!(out.begin_key, 130);
+    assert_eq!(out.end_key, 140);
+
+    let out = track.interpolate(&20)?;
+    assert_eq!(out.requested_key, 100);
+    assert_eq!(out.begin_key, 100);
+    assert_eq!(out.end_key, 110);
+
+    let out = track.interpolate(&-19)?;
+    assert_eq!(out.requested_key, 101);
+
+    track.set_loop_length(Some(20));
+    let out = track.interpolate(&135)?;
+    assert_eq!(out.requested_key, 115);
+    assert_eq!(out.begin_key, 110);
+    assert_eq!(out.end_key, 120);
+
+    track.set_loop_length(None);
+    assert_eq!(track.interpolate(&135).unwrap_err(), Error::KeyNotInRange);
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_loop_length() {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.set_loop_length(Some(0));
+}
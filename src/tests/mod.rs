@@ -1,8 +1,34 @@
 mod interpolator;
-
-use crate::{
-    *, 
-    tests::interpolator::*
+mod resample;
+mod transform;
+mod looping;
+mod reversed;
+mod interpolators;
+mod window;
+mod fallible;
+mod calculus;
+mod crossing;
+mod approach;
+mod aggregate;
+mod lod;
+mod lookup;
+mod update;
+mod transaction;
+mod journal;
+mod diff;
+mod fork;
+mod snapshot;
+mod sync;
+mod wait;
+mod observer;
+mod set;
+
+use {
+    std::ops::Deref,
+    crate::{
+        *, 
+        tests::interpolator::*
+    }
 };
 
 macro_rules! test_not_aligned {
@@ -216,6 +242,27 @@ fn test_insert_not_aligned() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_insert_not_aligned_inserted() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    for node in 0..4 {
+        track.push_aligned(node)?;
+    }
+
+    let inserted = track.insert_not_aligned(15, DataNA(15), |_| {})?;
+    assert_eq!(inserted, Inserted { old_key_end: 30, new_key_end: 15, key: 15, canceled: 2 });
+
+    let inserted = track.insert_not_aligned(12, DataNA(12), |_| {})?;
+    assert_eq!(inserted, Inserted { old_key_end: 15, new_key_end: 12, key: 12, canceled: 1 });
+
+    track.push_aligned(2)?;
+    let inserted = track.insert_not_aligned(17, DataNA(17), |_| {})?;
+    assert_eq!(inserted, Inserted { old_key_end: 20, new_key_end: 17, key: 17, canceled: 1 });
+
+    Ok(())
+}
+
 #[test]
 fn test_truncate_back() -> Result<()> {
     test_truncate_back_one_range()?;
@@ -479,6 +526,117 @@ fn test_interpolate() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_aligned_step_schedule() -> Result<()> {
+    let track_size = 8;
+    let track_step = 10;
+    let mut track = Track::new(Interpolator, track_size, track_step);
+
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.set_aligned_step_from(20, 5)?;
+    track.push_aligned(20)?;
+    assert_eq!(track.next_step, 5);
+    track.push_aligned(25)?;
+    track.push_aligned(30)?;
+    assert_eq!(track.key_end, 30);
+    assert_eq!(track.ranges.len(), 4);
+
+    assert_eq!(track.set_aligned_step_from(15, 2), Err(Error::KeyIsBehindEnd));
+    assert_eq!(track.set_aligned_step_from(33, 2), Err(Error::KeyIsNotAligned));
+
+    assert_eq!(track.range_index(&19), 1);
+    assert_eq!(track.range_index(&20), 2);
+    assert_eq!(track.range_index(&27), 3);
+
+    let out = track.interpolate(&27)?;
+    assert_eq!(out.begin_key, 25);
+    assert_eq!(*out.begin_node, 25);
+    assert_eq!(out.end_key, 30);
+    assert_eq!(*out.end_node, 30);
+
+    track.set_aligned_step_from(30, 2)?;
+    track.push_aligned(32)?;
+    assert_eq!(track.key_end, 32);
+
+    track.insert_not_aligned(31, DataNA(31), |_| {})?;
+    test_not_aligned_node(track.buf.last().unwrap(), 31, DataNA(31), 32, 32);
+    assert_eq!(track.next_step, 1);
+
+    track.push_aligned(32)?;
+    track.truncate_back(&26);
+    assert_eq!(track.key_start, 25);
+    assert_eq!(track.ranges.len(), 2);
+    assert_eq!(*track.buf[0], 25);
+
+    let out = track.interpolate(&26)?;
+    assert_eq!(out.begin_key, 25);
+    assert_eq!(*out.begin_node, 25);
+    assert_eq!(out.end_key, 30);
+    assert_eq!(*out.end_node, 30);
+
+    let out = track.interpolate(&31)?;
+    assert_eq!(out.begin_key, 31);
+    assert_eq!(*out.begin_node, 31);
+    assert_eq!(out.end_key, 32);
+    assert_eq!(*out.end_node, 32);
+
+    track.reset_track(0);
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    assert_eq!(track.key_end, 10);
+
+    Ok(())
+}
+
+#[test]
+fn test_aligned_step_from_not_aligned_end() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    for node in 0..4 {
+        track.push_aligned(node * 10)?;
+    }
+
+    // The not aligned end is exactly on the aligned key 20
+    track.insert_not_aligned(20, DataNA(20), |_| {})?;
+    track.set_aligned_step_from(20, 5)?;
+    assert_eq!(track.next_step, 0);
+
+    track.push_aligned(20)?;
+    track.push_aligned(25)?;
+    assert_eq!(track.key_end, 25);
+
+    // Canceling back to the not aligned end keeps the aligned key 20 next
+    track.cancel_forward(&20);
+    assert_eq!(track.key_end, 20);
+    assert_eq!(track.next_step, 0);
+
+    track.set_aligned_step_from(20, 5)?;
+    track.push_aligned(20)?;
+    track.push_aligned(25)?;
+    assert_eq!(track.key_end, 25);
+    assert_eq!(keys(&track), vec![0, 10, 20, 20, 25]);
+
+    track.insert_not_aligned(22, DataNA(22), |_| {})?;
+    track.set_aligned_step_from(25, 2)?;
+    assert_eq!(track.next_step, 3);
+
+    track.push_aligned(25)?;
+    track.push_aligned(27)?;
+    assert_eq!(track.key_end, 27);
+
+    track.cancel_forward(&23);
+    assert_eq!(track.key_end, 22);
+    assert_eq!(track.next_step, 3);
+
+    track.set_aligned_step_from(25, 4)?;
+    track.push_aligned(25)?;
+    track.push_aligned(29)?;
+    assert_eq!(track.key_end, 29);
+
+    Ok(())
+}
+
 #[test]
 fn test_range_index() {
     let track_size = 5;
@@ -1446,4 +1604,50 @@ fn test_cancel_aligned() -> Result<()> {
 //     assert_eq!(*track.buf[4], 8);
 
 //     Ok(())
-// }
\ No newline at end of file
+// }
+
+fn nodes(track: &Track<Interpolator>) -> Vec<(i64, usize)> {
+    track.nodes()
+        .map(|(key, node)| (key, **node))
+        .collect()
+}
+
+fn keys(track: &Track<Interpolator>) -> Vec<Key<Interpolator>> {
+    track.nodes()
+        .map(|(key, _)| key)
+        .collect()
+}
+
+fn make_track<I>(interpolator: I) -> Result<Track<I>>
+where
+    I: TrackInterpolator<Key = i64, Data = usize, NotAlignedData = DataNA>
+{
+    let mut track = Track::new(interpolator, 8, 10);
+
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.insert_not_aligned(3, DataNA(3), |_| {})?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.insert_not_aligned(14, DataNA(14), |_| {})?;
+    track.push_aligned(20)?;
+    track.push_aligned(30)?;
+
+    Ok(track)
+}
+
+#[derive(Debug, Clone)]
+struct NotAligned<V = f64>(V);
+
+impl<V> Deref for NotAligned<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.0
+    }
+}
+
+fn assert_near(lhs: f64, rhs: f64) {
+    assert!((lhs - rhs).abs() < 1e-9, "{} != {}", lhs, rhs);
+}
+
@@ -0,0 +1,142 @@
+use {
+    std::{
+        cell::Cell,
+        sync::{Arc, Mutex, mpsc},
+    },
+    crate::{
+        *,
+        tests::interpolator::*
+    }
+};
+
+type Events = Arc<Mutex<Vec<TrackEvent<i64>>>>;
+
+fn observe(track: &mut Track<Interpolator>) -> (usize, Events) {
+    let events = Events::default();
+    let observed = events.clone();
+    let id = track.add_observer(move |event| observed.lock().unwrap().push(event.clone()));
+
+    (id, events)
+}
+
+fn take(events: &Events) -> Vec<TrackEvent<i64>> {
+    std::mem::take(&mut *events.lock().unwrap())
+}
+
+#[test]
+fn test_observer() -> Result<()> {
+    let mut track = Track::new(Interpolator, 16, 10);
+    let (id, events) = observe(&mut track);
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    assert_eq!(take(&events), vec![
+        TrackEvent::Appended { begin: 0, end: 10 },
+        TrackEvent::Appended { begin: 10, end: 20 },
+    ]);
+
+    track.insert_not_aligned(15, DataNA(3), |_| {})?;
+    track.push_aligned(4)?;
+    assert_eq!(take(&events), vec![
+        TrackEvent::Canceled { begin: 10, end: 20 },
+        TrackEvent::Appended { begin: 10, end: 15 },
+        TrackEvent::Appended { begin: 15, end: 20 },
+    ]);
+
+    track.cancel_forward(&17);
+    track.truncate_back(&12);
+    track.update(&15, |_| ());
+    assert_eq!(take(&events), vec![
+        TrackEvent::Canceled { begin: 15, end: 20 },
+        TrackEvent::Truncated { begin: 0, end: 10 },
+        TrackEvent::Updated { begin: 10, end: 15, key: 15 },
+    ]);
+
+    track.reset_track(100);
+    assert_eq!(take(&events), vec![
+        TrackEvent::Reset { begin: 10, end: 15, key_start: 100 },
+    ]);
+
+    assert!(track.remove_observer(id));
+    assert!(!track.remove_observer(id));
+    track.push_aligned(5)?;
+    assert!(take(&events).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_observer_replaced() -> Result<()> {
+    let mut track = Track::new(Interpolator, 16, 10);
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+
+    let (_, events) = observe(&mut track);
+    track.shift(&5);
+    assert_eq!(take(&events), vec![
+        TrackEvent::Reset { begin: 0, end: 10, key_start: 5 },
+        TrackEvent::Appended { begin: 5, end: 15 },
+    ]);
+
+    let mut fork = track.fork();
+    fork.push_aligned(2)?;
+    track.commit(fork)?;
+    assert_eq!(take(&events), vec![
+        TrackEvent::Reset { begin: 5, end: 15, key_start: 5 },
+        TrackEvent::Appended { begin: 5, end: 25 },
+    ]);
+
+    track.reversed().truncate_back(&10);
+    assert_eq!(take(&events), vec![
+        TrackEvent::Canceled { begin: 15, end: 25 },
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_observer_transaction() -> Result<()> {
+    let mut track = Track::new(Interpolator, 3, 10);
+    let (_, events) = observe(&mut track);
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    take(&events);
+
+    let result = track.transaction()
+        .push_aligned(2)
+        .push_aligned(3)
+        .commit();
+    assert_eq!(result.unwrap_err(), Error::Overflow);
+    assert_eq!(take(&events), vec![]);
+
+    track.transaction()
+        .push_aligned(2)
+        .insert_not_aligned(15, DataNA(3))
+        .commit()?;
+    assert_eq!(take(&events), vec![
+        TrackEvent::Appended { begin: 10, end: 20 },
+        TrackEvent::Canceled { begin: 10, end: 20 },
+        TrackEvent::Appended { begin: 10, end: 15 },
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_observer_not_sync() -> Result<()> {
+    let mut track = Track::new(Interpolator, 16, 10);
+    let (sender, receiver) = mpsc::channel();
+    let count = Cell::new(0);
+    track.add_observer(move |_| {
+        count.set(count.get() + 1);
+        sender.send(count.get()).unwrap();
+    });
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), vec![1]);
+
+    Ok(())
+}
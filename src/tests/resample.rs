@@ -0,0 +1,113 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        test_not_aligned_node,
+        nodes,
+    }
+};
+
+fn lerp(out: crate::tests::interpolator::Output) -> Data<Interpolator> {
+    let begin = *out.begin_node as i64;
+    let end = *out.end_node as i64;
+    let distance = out.end_key - out.begin_key;
+
+    (begin + (end - begin) * (out.requested_key - out.begin_key) / distance) as Data<Interpolator>
+}
+
+#[test]
+fn test_nodes() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    assert!(nodes(&track).is_empty());
+
+    track.push_aligned(0)?;
+    assert_eq!(nodes(&track), vec![(0, 0)]);
+
+    track.push_aligned(10)?;
+    track.insert_not_aligned(3, DataNA(3), |_| {})?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.insert_not_aligned(14, DataNA(14), |_| {})?;
+    assert_eq!(nodes(&track), vec![(0, 0), (3, 3), (10, 10), (14, 14)]);
+
+    track.push_aligned(20)?;
+    track.push_aligned(30)?;
+    track.truncate_back(&10);
+    assert_eq!(nodes(&track), vec![(10, 10), (14, 14), (20, 20), (30, 30)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_resample() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.push_aligned(30)?;
+    track.push_aligned(40)?;
+    track.insert_not_aligned(34, DataNA(34), |_| {})?;
+
+    let fine = track.resample(5, lerp)?;
+    assert_eq!(*fine.key_start(), 0);
+    assert_eq!(*fine.key_end(), 34);
+    assert_eq!(
+        nodes(&fine),
+        vec![(0, 0), (5, 5), (10, 10), (15, 15), (20, 20), (25, 25), (30, 30), (34, 34)]
+    );
+    test_not_aligned_node(fine.node_end().unwrap(), 34, DataNA(34), 35, 34);
+
+    let coarse = track.resample(20, lerp)?;
+    assert_eq!(*coarse.key_end(), 34);
+    assert_eq!(nodes(&coarse), vec![(0, 0), (20, 20), (34, 34)]);
+    test_not_aligned_node(coarse.node_end().unwrap(), 34, DataNA(34), 40, 34);
+
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.reset_track(5);
+    track.push_aligned(5)?;
+    track.push_aligned(15)?;
+    track.insert_not_aligned(10, DataNA(10), |_| {})?;
+    track.push_aligned(15)?;
+    track.insert_not_aligned(12, DataNA(12), |_| {})?;
+    track.push_aligned(15)?;
+    track.push_aligned(25)?;
+
+    let resampled = track.resample(5, lerp)?;
+    assert_eq!(*resampled.key_end(), 25);
+    assert_eq!(nodes(&resampled), vec![(5, 5), (10, 10), (12, 12), (15, 15), (20, 20), (25, 25)]);
+    assert!(matches![resampled.nodes().nth(1).unwrap().1, TrackNode::Aligned(_)]);
+    assert!(matches![resampled.nodes().nth(2).unwrap().1, TrackNode::NotAligned(_)]);
+
+    let resampled = track.resample(4, lerp)?;
+    assert_eq!(*resampled.key_end(), 25);
+    assert_eq!(
+        nodes(&resampled),
+        vec![(5, 5), (9, 9), (10, 10), (12, 12), (13, 13), (17, 17), (21, 21), (25, 25)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_resample_not_aligned_end() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    for node in 0..5 {
+        track.push_aligned(node * 10)?;
+    }
+
+    let resampled = track.resample(15, lerp)?;
+    assert_eq!(*resampled.key_end(), 40);
+    assert_eq!(nodes(&resampled), vec![(0, 0), (15, 15), (30, 30), (40, 40)]);
+    test_not_aligned_node(resampled.node_end().unwrap(), 40, DataNA(40), 45, 40);
+
+    track.insert_not_aligned(34, DataNA(34), |_| {})?;
+    track.push_aligned(40)?;
+
+    let resampled = track.resample(15, lerp)?;
+    assert_eq!(*resampled.key_end(), 40);
+    assert_eq!(nodes(&resampled), vec![(0, 0), (15, 15), (30, 30), (34, 34), (40, 40)]);
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        keys,
+        make_track,
+    }
+};
+
+#[test]
+fn test_reversed_interpolate() -> Result<()> {
+    let mut track = make_track(Interpolator)?;
+    let mut reversed = track.reversed();
+
+    assert_eq!(*reversed.key_start(), 30);
+    assert_eq!(*reversed.key_end(), 0);
+
+    assert_eq!(reversed.interpolate(&31).unwrap_err(), Error::KeyNotInRange);
+    assert_eq!(reversed.interpolate(&0).unwrap_err(), Error::KeyNotInRange);
+
+    let cases = vec![
+        (30, 30, 20),
+        (25, 30, 20),
+        (20, 20, 14),
+        (14, 14, 10),
+        (12, 14, 10),
+        (10, 10, 3),
+        (3, 3, 0),
+        (1, 3, 0),
+    ];
+
+    for (key, begin_key, end_key) in cases {
+        let out = reversed.interpolate(&key)?;
+        assert_eq!(out.requested_key, key);
+        assert_eq!(out.begin_key, begin_key);
+        assert_eq!(*out.begin_node, begin_key as Data<Interpolator>);
+        assert_eq!(out.end_key, end_key);
+        assert_eq!(*out.end_node, end_key as Data<Interpolator>);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_reversed_truncate_back() -> Result<()> {
+    let mut track = make_track(Interpolator)?;
+
+    track.reversed().truncate_back(&40);
+    assert_eq!(keys(&track), vec![0, 3, 10, 14, 20, 30]);
+
+    track.reversed().truncate_back(&21);
+    assert_eq!(keys(&track), vec![0, 3, 10, 14, 20, 30]);
+
+    track.reversed().truncate_back(&12);
+    assert_eq!(keys(&track), vec![0, 3, 10, 14, 20]);
+    assert_eq!(*track.key_end(), 20);
+
+    track.reversed().truncate_back(&10);
+    assert_eq!(keys(&track), vec![0, 3, 10]);
+    assert_eq!(*track.key_end(), 10);
+
+    track.reversed().truncate_back(&-10);
+    assert_eq!(keys(&track), vec![0, 3, 10]);
+
+    track.push_aligned(20)?;
+    assert_eq!(keys(&track), vec![0, 3, 10, 20]);
+
+    let mut track = make_track(Interpolator)?;
+    track.reversed().truncate_back(&-10);
+    assert_eq!(keys(&track), vec![0, 3, 10]);
+
+    Ok(())
+}
+
+#[test]
+fn test_reversed_cancel_forward() -> Result<()> {
+    let mut track = make_track(Interpolator)?;
+    assert!(track.reversed().cancel_forward(&-1).is_empty());
+
+    let canceled = track.reversed().cancel_forward(&3)
+        .map(|node| **node)
+        .collect::<Vec<_>>();
+    assert_eq!(canceled, vec![0, 3]);
+    assert_eq!(*track.key_start(), 10);
+    assert_eq!(keys(&track), vec![10, 14, 20, 30]);
+
+    let mut track = make_track(Interpolator)?;
+    let canceled = track.reversed().cancel_forward(&10)
+        .map(|node| **node)
+        .collect::<Vec<_>>();
+    assert_eq!(canceled, vec![0, 3, 10, 14]);
+    assert_eq!(keys(&track), vec![20, 30]);
+
+    let out = track.interpolate(&25)?;
+    assert_eq!(out.begin_key, 20);
+    assert_eq!(out.end_key, 30);
+
+    let canceled = track.reversed().cancel_forward(&25)
+        .map(|node| **node)
+        .collect::<Vec<_>>();
+    assert_eq!(canceled, vec![20]);
+    assert_eq!(keys(&track), vec![30]);
+
+    track.push_aligned(40)?;
+    assert_eq!(keys(&track), vec![30, 40]);
+
+    let mut track = make_track(Interpolator)?;
+    track.push_aligned(40)?;
+    track.insert_not_aligned(34, DataNA(34), |_| {})?;
+
+    assert_eq!(track.reversed().cancel_forward(&31).len(), 6);
+    assert_eq!(keys(&track), vec![34]);
+    assert!(matches![track.node_start().unwrap(), TrackNode::Aligned(34)]);
+
+    track.push_aligned(44)?;
+    assert_eq!(keys(&track), vec![34, 44]);
+
+    assert_eq!(track.reversed().cancel_forward(&44).len(), 2);
+    assert!(track.is_empty());
+    assert_eq!(*track.key_start(), 44);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use crate::{
+    *,
+    tests::interpolator::*
+};
+
+fn set() -> TrackSet<&'static str, Interpolator> {
+    TrackSet::new(Interpolator, 8, 10)
+}
+
+#[test]
+fn test_track_set() -> Result<()> {
+    let mut set = set();
+
+    set.push_aligned_all(vec![("a", 0), ("b", 10)])?;
+    set.push_aligned_all(vec![("a", 1), ("c", 20)])?;
+    set.push_aligned_all(vec![("b", 11)])?;
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.next_key(), &30);
+
+    assert_eq!(set.get(&"a").unwrap().key_end(), &20);
+    assert_eq!(set.get(&"c").unwrap().key_start(), &10);
+
+    let outputs = set.interpolate_all(&5)?;
+    assert_eq!(outputs.keys().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(*outputs[&"a"].end_node, 1);
+    assert_eq!(*outputs[&"b"].end_node, 10);
+
+    let outputs = set.interpolate_all(&15)?;
+    assert_eq!(*outputs[&"a"].end_node, 1);
+    assert_eq!(*outputs[&"b"].end_node, 11);
+    assert_eq!(*outputs[&"c"].begin_node, 20);
+
+    assert_eq!(set.changed_between(&0, &5), Vec::<&str>::new());
+    assert_eq!(set.changed_between(&0, &10), vec!["a", "c"]);
+    assert_eq!(set.changed_between(&10, &20), vec!["b"]);
+    assert_eq!(set.changed_between(&15, &40), vec!["b"]);
+    assert_eq!(set.changed_between(&20, &40), Vec::<&str>::new());
+
+    set.truncate_back(&15);
+    assert!(set.iter().all(|(_, track)| track.key_start() == &10));
+
+    set.reset(100);
+    assert!(set.interpolate_all(&15)?.is_empty());
+
+    set.push_aligned_all(vec![("b", 12)])?;
+    set.push_aligned_all(vec![("a", 2)])?;
+    assert_eq!(set.get(&"a").unwrap().key_start(), &110);
+    assert_eq!(*set.interpolate_all(&105)?[&"b"].end_node, 12);
+    assert!(set.remove(&"c").is_some());
+    assert_eq!(set.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_track_set_overflow() -> Result<()> {
+    let mut set = set();
+
+    set.push_aligned_all(vec![("b", 0)])?;
+    for node in 1..8 {
+        set.push_aligned_all(vec![("a", node), ("b", node)])?;
+    }
+
+    assert_eq!(set.push_aligned_all(vec![("a", 8), ("b", 8), ("c", 8)]), Err(Error::Overflow));
+    assert_eq!(set.next_key(), &80);
+    assert!(set.get(&"c").is_none());
+    assert_eq!(set.get(&"a").unwrap().key_end(), &70);
+    assert_eq!(set.get(&"b").unwrap().key_end(), &70);
+
+    set.truncate_back(&25);
+    set.push_aligned_all(vec![("a", 8), ("b", 8)])?;
+    assert_eq!(set.get(&"a").unwrap().key_end(), &80);
+    assert_eq!(*set.interpolate_all(&75)?[&"b"].end_node, 8);
+
+    set.reset(200);
+    for node in 0..8 {
+        set.push_aligned_all(vec![("b", node)])?;
+    }
+
+    assert!(set.get(&"a").unwrap().is_empty());
+    let versions = set.iter().map(|(_, track)| track.version()).collect::<Vec<_>>();
+    assert_eq!(set.push_aligned_all(vec![("a", 8), ("b", 8)]), Err(Error::Overflow));
+    assert!(set.get(&"a").unwrap().is_empty());
+    assert_eq!(set.get(&"a").unwrap().key_start(), &200);
+    assert_eq!(set.iter().map(|(_, track)| track.version()).collect::<Vec<_>>(), versions);
+
+    Ok(())
+}
+
+#[test]
+fn test_track_set_update() -> Result<()> {
+    let mut set = set();
+    set.push_aligned_all(vec![("a", 0), ("b", 10)])?;
+    set.push_aligned_all(vec![("a", 1)])?;
+
+    let corrected = set.update(&"b", &0, |node| match node {
+        TrackNodeMut::Aligned(data) => *data = 12,
+        TrackNodeMut::NotAligned(_) => unreachable!()
+    });
+    assert!(corrected.is_some());
+    assert!(set.update(&"b", &5, |_| ()).is_none());
+    assert!(set.update(&"c", &0, |_| ()).is_none());
+
+    assert_eq!(*set.interpolate_all(&5)?[&"b"].begin_node, 12);
+    assert_eq!(set.changed_between(&0, &10), vec!["a", "b"]);
+
+    Ok(())
+}
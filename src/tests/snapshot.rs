@@ -0,0 +1,79 @@
+use {
+    std::{
+        sync::mpsc,
+        thread,
+    },
+    crate::{
+        *,
+        tests::{
+            interpolator::*,
+            aggregate::MinMax,
+        }
+    }
+};
+
+#[test]
+fn test_snapshot() -> Result<()> {
+    let mut track = Track::new(Interpolator, 128, 10);
+    track.enable_journal();
+    track.push_aligned(0)?;
+
+    let (sender, receiver) = mpsc::channel::<TrackSnapshot<Interpolator>>();
+    let reader = thread::spawn(move || {
+        let mut last_key_end = 0;
+
+        for mut snapshot in receiver {
+            assert!(*snapshot.key_end() >= last_key_end);
+            assert!(snapshot.journal().is_none());
+            last_key_end = *snapshot.key_end();
+
+            let keys = (0..*snapshot.key_end()).step_by(7).collect::<Vec<_>>();
+            for key in keys {
+                let out = snapshot.interpolate(&key).unwrap();
+                assert_eq!(*out.begin_node as i64, key / 10);
+            }
+        }
+
+        last_key_end
+    });
+
+    for node in 1..100 {
+        track.push_aligned(node)?;
+
+        if node % 10 == 0 {
+            sender.send(track.snapshot()).unwrap();
+        }
+    }
+
+    let snapshot = track.snapshot();
+    track.cancel_forward(&500);
+    assert_eq!(snapshot.key_end(), &990);
+    assert_eq!(snapshot.nodes().count(), 100);
+
+    drop(sender);
+    assert_eq!(reader.join().unwrap(), 900);
+
+    Ok(())
+}
+
+#[test]
+fn test_snapshot_aggregate() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.enable_aggregate(MinMax);
+    track.push_aligned(5)?;
+    track.push_aligned(3)?;
+    track.push_aligned(8)?;
+
+    let snapshot = track.snapshot();
+    assert_eq!(snapshot.aggregate::<MinMax>(&0, &20), Some((3, 8)));
+
+    track.push_aligned(1)?;
+    track.push_aligned(9)?;
+
+    assert_eq!(snapshot.aggregate::<MinMax>(&0, &20), Some((3, 8)));
+    assert_eq!(snapshot.sample_lod::<MinMax>(&0, &20, 1), vec![(0, (3, 8))]);
+    assert_eq!(track.aggregate::<MinMax>(&0, &40), Some((1, 9)));
+    assert_eq!(snapshot.aggregate::<MinMax>(&0, &40), Some((3, 8)));
+
+    Ok(())
+}
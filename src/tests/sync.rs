@@ -0,0 +1,103 @@
+use {
+    std::thread,
+    crate::{
+        *,
+        tests::interpolator::*
+    }
+};
+
+#[test]
+fn test_sync_track() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 16, 10));
+    let mut reader = track.reader();
+    assert!(reader.view().is_empty());
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    assert_eq!(reader.view().key_end(), &0);
+
+    assert_eq!(*reader.interpolate(&15)?.begin_node, 1);
+    assert_eq!(reader.view().key_end(), &20);
+
+    track.insert_not_aligned(15, DataNA(10), |_| {})?;
+    track.push_aligned(3)?;
+    let mut other_reader = reader.clone();
+
+    assert_eq!(reader.interpolate(&12).unwrap_err(), Error::Invalidated);
+    assert_eq!(*reader.interpolate(&12)?.end_node, 10);
+    assert_eq!(other_reader.refresh(), Some(Invalidated { begin: 10, end: 20 }));
+    assert_eq!(other_reader.refresh(), None);
+
+    track.cancel_forward(&17);
+    assert_eq!(*reader.interpolate(&5)?.end_node, 1);
+    assert_eq!(reader.view().key_end(), &15);
+
+    track.truncate_back(&10);
+    assert_eq!(other_reader.refresh(), Some(Invalidated { begin: 0, end: 20 }));
+    assert_eq!(reader.interpolate(&5).unwrap_err(), Error::Invalidated);
+    assert_eq!(reader.interpolate(&5).unwrap_err(), Error::KeyNotInRange);
+
+    track.reset_track(100);
+    assert_eq!(reader.refresh(), Some(Invalidated { begin: 10, end: 15 }));
+    assert!(reader.view().is_empty());
+    assert_eq!(track.track().key_start(), &100);
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_track_forgotten_invalidations() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 16, 10));
+    let mut reader = track.reader();
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    reader.refresh();
+
+    for _ in 0..100 {
+        track.cancel_forward(&10);
+        track.push_aligned(1)?;
+    }
+
+    assert_eq!(reader.refresh(), Some(Invalidated { begin: 0, end: 10 }));
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_track_threads() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 1024, 10));
+    track.push_aligned(0)?;
+
+    let readers = (0..4)
+        .map(|_| {
+            let mut reader = track.reader();
+
+            thread::spawn(move || {
+                let mut key = 0;
+
+                while key < 5000 {
+                    match reader.interpolate(&key) {
+                        Ok(out) => {
+                            assert_eq!(*out.begin_node as i64, key / 10);
+                            key += 3;
+                        },
+                        Err(Error::KeyNotInRange) => thread::yield_now(),
+                        Err(err) => panic!("unexpected error: {:?}", err)
+                    }
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for node in 1..=500 {
+        track.push_aligned(node)?;
+    }
+
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    Ok(())
+}
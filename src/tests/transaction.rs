@@ -0,0 +1,73 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        aggregate::MinMax,
+        nodes,
+    }
+};
+
+#[test]
+fn test_transaction_commit() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    track.transaction()
+        .push_aligned(0)
+        .push_aligned(1)
+        .push_aligned(2)
+        .commit()?;
+
+    let insertions = track.transaction()
+        .insert_not_aligned(15, DataNA(3))
+        .push_aligned(4)
+        .cancel_forward(20)
+        .push_aligned(5)
+        .commit()?;
+
+    assert_eq!(nodes(&track), vec![(0, 0), (10, 1), (15, 3), (20, 5)]);
+    assert_eq!(track.key_end(), &20);
+
+    assert_eq!(insertions.len(), 1);
+    let (inserted, canceled) = &insertions[0];
+    assert_eq!(inserted, &Inserted { old_key_end: 20, new_key_end: 15, key: 15, canceled: 1 });
+    assert_eq!(canceled.iter().map(|node| **node).collect::<Vec<_>>(), vec![2]);
+
+    Ok(())
+}
+
+#[test]
+fn test_transaction_rollback() -> Result<()> {
+    let mut track = Track::new(Interpolator, 3, 10);
+    track.enable_aggregate(MinMax);
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+
+    let result = track.transaction()
+        .insert_not_aligned(15, DataNA(30))
+        .push_aligned(40)
+        .commit();
+    assert_eq!(result.unwrap_err(), Error::Overflow);
+
+    assert_eq!(nodes(&track), vec![(0, 0), (10, 1), (20, 2)]);
+    assert_eq!(track.key_end(), &20);
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((0, 2)));
+
+    let out = track.interpolate(&15)?;
+    assert_eq!(out.begin_key, 10);
+    assert_eq!(out.end_key, 20);
+
+    let result = track.transaction()
+        .cancel_forward(10)
+        .insert_not_aligned(30, DataNA(3))
+        .commit();
+    assert_eq!(result.unwrap_err(), Error::KeyIsNotInInnerRange);
+    assert_eq!(nodes(&track), vec![(0, 0), (10, 1), (20, 2)]);
+
+    track.truncate_back(&10);
+    track.push_aligned(3)?;
+    assert_eq!(nodes(&track), vec![(10, 1), (20, 2), (30, 3)]);
+
+    Ok(())
+}
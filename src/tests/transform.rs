@@ -0,0 +1,136 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        test_not_aligned_node,
+        keys,
+    }
+};
+
+fn make_transform_track() -> Result<Track<Interpolator>> {
+    let mut track = Track::new(Interpolator, 8, 10);
+
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.insert_not_aligned(4, DataNA(4), |_| {})?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.push_aligned(30)?;
+    track.insert_not_aligned(26, DataNA(26), |_| {})?;
+
+    Ok(track)
+}
+
+#[test]
+fn test_shift() -> Result<()> {
+    let mut track = make_transform_track()?;
+
+    track.shift(&100);
+    assert_eq!(*track.key_start(), 100);
+    assert_eq!(*track.key_end(), 126);
+    assert_eq!(keys(&track), vec![100, 104, 110, 120, 126]);
+    test_not_aligned_node(&track.buf[1], 104, DataNA(4), 110, 10);
+    test_not_aligned_node(&track.buf[4], 126, DataNA(26), 130, 30);
+
+    match track.node_at(&126) {
+        Some((126, TrackNode::NotAligned(node))) => assert_eq!(*node.canceled_key(), 130),
+        _ => panic!("expected not aligned node")
+    }
+
+    let out = track.interpolate(&107)?;
+    assert_eq!(out.begin_key, 104);
+    assert_eq!(*out.begin_node, 4);
+    assert_eq!(out.end_key, 110);
+
+    track.push_aligned(30)?;
+    assert_eq!(*track.key_end(), 130);
+    track.insert_not_aligned(125, DataNA(25), |_| {})?;
+    test_not_aligned_node(track.node_end().unwrap(), 125, DataNA(25), 130, 30);
+
+    track.shift(&-125);
+    assert_eq!(*track.key_start(), -25);
+    assert_eq!(keys(&track), vec![-25, -21, -15, -5, 0]);
+    test_not_aligned_node(&track.buf[1], -21, DataNA(4), -15, 10);
+    test_not_aligned_node(track.node_end().unwrap(), 0, DataNA(25), 5, 30);
+
+    Ok(())
+}
+
+#[test]
+fn test_shift_after_truncation() -> Result<()> {
+    let mut track = make_transform_track()?;
+
+    track.truncate_back(&15);
+    track.cancel_forward(&25);
+    track.push_aligned(30)?;
+    track.push_aligned(40)?;
+    track.insert_not_aligned(33, DataNA(33), |_| {})?;
+    assert_eq!(keys(&track), vec![10, 20, 30, 33]);
+
+    track.shift(&100);
+    assert_eq!(keys(&track), vec![110, 120, 130, 133]);
+    test_not_aligned_node(track.node_end().unwrap(), 133, DataNA(33), 140, 40);
+
+    track.reset_track(0);
+    track.push_aligned(0)?;
+    track.push_aligned(10)?;
+    track.push_aligned(20)?;
+    track.insert_not_aligned(15, DataNA(15), |_| {})?;
+
+    track.shift(&-5);
+    assert_eq!(keys(&track), vec![-5, 5, 10]);
+    test_not_aligned_node(track.node_end().unwrap(), 10, DataNA(15), 15, 20);
+
+    Ok(())
+}
+
+#[test]
+fn test_scale_time() -> Result<()> {
+    let mut track = make_transform_track()?;
+    track.shift(&10);
+
+    track.scale_time(0.5)?;
+    assert_eq!(track.aligned_step, 5);
+    assert_eq!(*track.key_start(), 10);
+    assert_eq!(*track.key_end(), 23);
+    assert_eq!(keys(&track), vec![10, 12, 15, 20, 23]);
+    test_not_aligned_node(&track.buf[4], 23, DataNA(26), 25, 30);
+
+    let out = track.interpolate(&16)?;
+    assert_eq!(out.begin_key, 15);
+    assert_eq!(*out.begin_node, 10);
+    assert_eq!(out.end_key, 20);
+
+    track.push_aligned(30)?;
+    assert_eq!(*track.key_end(), 25);
+
+    track.scale_time(4.0)?;
+    assert_eq!(track.aligned_step, 20);
+    assert_eq!(keys(&track), vec![10, 18, 30, 50, 62, 70]);
+
+    track.push_aligned(40)?;
+    assert_eq!(*track.key_end(), 90);
+
+    Ok(())
+}
+
+#[test]
+fn test_scale_time_rounding() -> Result<()> {
+    let mut track = make_transform_track()?;
+
+    track.scale_time(0.25)?;
+    assert_eq!(track.aligned_step, 3);
+    assert_eq!(keys(&track), vec![0, 1, 3, 6, 8]);
+    assert_eq!(*track.key_end(), 8);
+
+    track.push_aligned(30)?;
+    assert_eq!(*track.key_end(), 9);
+
+    let mut track = make_transform_track()?;
+    assert_eq!(track.scale_time(0.1), Err(Error::ScaleCollapsesKeys));
+    assert_eq!(track.scale_time(0.04), Err(Error::ScaleCollapsesKeys));
+    assert_eq!(track.aligned_step, 10);
+    assert_eq!(keys(&track), vec![0, 4, 10, 20, 26]);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::{
+    *,
+    tests::{
+        interpolator::*,
+        aggregate::MinMax,
+    }
+};
+
+#[test]
+fn test_update() -> Result<()> {
+    let mut track = Track::new(Interpolator, 8, 10);
+    track.enable_aggregate(MinMax);
+
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+    track.push_aligned(2)?;
+    track.insert_not_aligned(13, DataNA(3), |_| {})?;
+    track.push_aligned(4)?;
+
+    let updated = track.update(&10, |node| match node {
+        TrackNodeMut::Aligned(data) => {
+            *data = 10;
+            true
+        },
+        TrackNodeMut::NotAligned(_) => false
+    });
+    assert_eq!(updated, Some(true));
+
+    let updated = track.update(&13, |node| match node {
+        TrackNodeMut::Aligned(_) => false,
+        TrackNodeMut::NotAligned(data) => {
+            data.0 = 30;
+            true
+        }
+    });
+    assert_eq!(updated, Some(true));
+
+    assert_eq!(track.update(&15, |_| ()), None);
+    assert_eq!(track.update(&30, |_| ()), None);
+
+    let out = track.interpolate(&12)?;
+    assert_eq!(*out.begin_node, 10);
+    assert_eq!(*out.end_node, 30);
+    assert_eq!(track.key_end(), &20);
+
+    tests::test_not_aligned_node(&out.end_node, 13, DataNA(30), 20, 2);
+    assert_eq!(track.aggregate::<MinMax>(&0, &20), Some((0, 30)));
+
+    Ok(())
+}
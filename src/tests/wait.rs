@@ -0,0 +1,109 @@
+use {
+    std::{
+        thread::{self, Thread},
+        sync::Arc,
+        future::Future,
+        task::{Context, Poll, Wake},
+        time::Duration,
+    },
+    crate::{
+        *,
+        tests::interpolator::*
+    }
+};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn test_wait_for() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 64, 10));
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+
+    let reader = track.reader();
+    reader.wait_for(&5)?;
+
+    let waiter = thread::spawn(move || {
+        let mut reader = reader;
+        reader.wait_for(&155).unwrap();
+        *reader.interpolate(&155).unwrap().begin_node
+    });
+
+    for node in 2..20 {
+        thread::sleep(Duration::from_millis(1));
+        track.push_aligned(node)?;
+    }
+
+    assert_eq!(waiter.join().unwrap(), 15);
+
+    Ok(())
+}
+
+#[test]
+fn test_wait_for_async() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 64, 10));
+    track.push_aligned(0)?;
+
+    let mut reader = track.reader();
+    let writer = thread::spawn(move || {
+        for node in 1..20 {
+            thread::sleep(Duration::from_millis(1));
+            track.push_aligned(node).unwrap();
+        }
+
+        track
+    });
+
+    block_on(reader.wait_for_async(95))?;
+    assert_eq!(*reader.interpolate(&95)?.begin_node, 9);
+
+    let track = writer.join().unwrap();
+    assert_eq!(block_on(reader.wait_for_async(185)), Ok(()));
+    assert_eq!(track.track().key_end(), &190);
+
+    Ok(())
+}
+
+#[test]
+fn test_wait_for_truncated() -> Result<()> {
+    let mut track = SyncTrack::new(Track::new(Interpolator, 64, 10));
+    track.push_aligned(0)?;
+    track.push_aligned(1)?;
+
+    let reader = track.reader();
+    let waiter = thread::spawn(move || reader.wait_for(&35));
+
+    thread::sleep(Duration::from_millis(10));
+    track.reset_track(100);
+
+    assert_eq!(waiter.join().unwrap(), Err(Error::KeyNotInRange));
+
+    let reader = track.reader();
+    let waiter = thread::spawn(move || block_on(reader.wait_for_async(135)));
+
+    thread::sleep(Duration::from_millis(10));
+    drop(track);
+
+    assert_eq!(waiter.join().unwrap(), Err(Error::KeyNotInRange));
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use {
+    std::convert::Infallible,
+    crate::{
+        *,
+        tests::{
+            interpolator::DataNA,
+            make_track,
+        },
+    },
+};
+
+#[derive(Clone)]
+struct WindowInterpolator;
+
+impl TrackInterpolator for WindowInterpolator {
+    type Key = i64;
+    type Data = usize;
+    type NotAlignedData = DataNA;
+    type Output = (Vec<(i64, usize)>, usize);
+    type Error = Infallible;
+
+    const WINDOW: usize = 2;
+
+    fn interpolate(
+        &mut self, 
+        _: &i64, 
+        _: i64,
+        _: &TrackNode<i64, usize, DataNA>, 
+        _: i64,
+        _: &TrackNode<i64, usize, DataNA>
+    ) -> std::result::Result<Self::Output, Infallible> { 
+        unreachable!()
+    }
+
+    fn interpolate_window(
+        &mut self,
+        _: &i64,
+        window: &TrackWindow<'_, i64, usize, DataNA>
+    ) -> std::result::Result<Self::Output, Infallible> {
+        let nodes = window.nodes()
+            .iter()
+            .map(|(key, node)| (*key, ***node))
+            .collect();
+
+        Ok((nodes, window.lhs_index()))
+    }
+}
+
+#[test]
+fn test_window() -> Result<()> {
+    let mut track = make_track(WindowInterpolator)?;
+
+    let (nodes, lhs_index) = track.interpolate(&1)?;
+    assert_eq!(nodes, vec![(0, 0), (3, 3), (10, 10), (14, 14)]);
+    assert_eq!(lhs_index, 0);
+
+    let (nodes, lhs_index) = track.interpolate(&12)?;
+    assert_eq!(nodes, vec![(0, 0), (3, 3), (10, 10), (14, 14), (20, 20), (30, 30)]);
+    assert_eq!(lhs_index, 2);
+
+    let (nodes, lhs_index) = track.interpolate(&10)?;
+    assert_eq!(nodes, vec![(0, 0), (3, 3), (10, 10), (14, 14), (20, 20), (30, 30)]);
+    assert_eq!(lhs_index, 2);
+
+    let (nodes, lhs_index) = track.interpolate(&25)?;
+    assert_eq!(nodes, vec![(10, 10), (14, 14), (20, 20), (30, 30)]);
+    assert_eq!(lhs_index, 2);
+
+    track.truncate_back(&15);
+    let (nodes, lhs_index) = track.interpolate(&15)?;
+    assert_eq!(nodes, vec![(10, 10), (14, 14), (20, 20), (30, 30)]);
+    assert_eq!(lhs_index, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_window_looping_and_reversed() -> Result<()> {
+    let mut track = make_track(WindowInterpolator)?;
+    track.set_loop_length(Some(40));
+
+    let (nodes, lhs_index) = track.interpolate(&35)?;
+    assert_eq!(nodes, vec![(14, 14), (20, 20), (30, 30), (40, 0), (43, 3), (50, 10)]);
+    assert_eq!(lhs_index, 2);
+
+    track.set_loop_length(None);
+
+    let (nodes, lhs_index) = track.reversed().interpolate(&10)?;
+    assert_eq!(nodes, vec![(0, 0), (3, 3), (10, 10), (14, 14), (20, 20)]);
+    assert_eq!(lhs_index, 1);
+
+    Ok(())
+}
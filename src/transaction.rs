@@ -0,0 +1,148 @@
+use {
+    std::collections::VecDeque,
+    crate::{
+        buf::Buffer,
+        schedule::StepSchedule,
+        Track,
+        TrackInterpolator,
+        TrackMutation,
+        TrackRange,
+        Result,
+        Inserted,
+        Key,
+        KeyDistance,
+        Data,
+        NotAlignedData,
+        Node,
+        Mutation,
+    }
+};
+
+/// Result of an insertion made by the transaction with the nodes canceled by it
+type Insertion<I> = (Inserted<Key<I>>, Vec<Node<I>>);
+
+/// Mutations which are applied to the track all together by the `commit`.
+/// Mutations which are not committed are dropped with the transaction.
+#[must_use = "mutations are applied only by the `commit`"]
+pub struct Transaction<'t, I: TrackInterpolator> {
+    track: &'t mut Track<I>,
+    mutations: Vec<Mutation<I>>,
+}
+
+impl<'t, I: TrackInterpolator> Transaction<'t, I> {
+    #[must_use = "mutations are applied only by the `commit`"]
+    pub fn push_aligned(&mut self, node: Data<I>) -> &mut Self {
+        self.mutations.push(TrackMutation::PushAligned(node));
+        self
+    }
+
+    /// The insertion result and the canceled nodes are returned by the `commit`
+    #[must_use = "mutations are applied only by the `commit`"]
+    pub fn insert_not_aligned(&mut self, key: Key<I>, node: NotAlignedData<I>) -> &mut Self {
+        self.mutations.push(TrackMutation::InsertNotAligned(key, node));
+        self
+    }
+
+    #[must_use = "mutations are applied only by the `commit`"]
+    pub fn cancel_forward(&mut self, key: Key<I>) -> &mut Self {
+        self.mutations.push(TrackMutation::CancelForward(key));
+        self
+    }
+
+    /// Applies the mutations in order.
+    /// Returns the result of every insertion with the nodes canceled by it, in order.
+    /// If any of the mutations fails, the track is rolled back to the state before the transaction.
+    /// Observers are notified only after every mutation succeeds.
+    pub fn commit(&mut self) -> Result<Vec<Insertion<I>>> {
+        let state = TrackState::save(self.track);
+        let mut insertions = vec![];
+
+        self.track.deferred_events = Some(vec![]);
+        let result = std::mem::take(&mut self.mutations)
+            .into_iter()
+            .try_for_each(|mutation| match mutation {
+                TrackMutation::InsertNotAligned(key, node) => {
+                    let mut canceled = vec![];
+                    let inserted = self.track.insert_not_aligned(key, node, |node| canceled.push(node.clone()))?;
+                    insertions.push((inserted, canceled));
+
+                    Ok(())
+                },
+                mutation => self.track.apply_mutation(mutation)
+            });
+        let events = self.track.deferred_events.take().unwrap_or_default();
+
+        if let Err(err) = result {
+            state.restore(self.track);
+
+            return Err(err);
+        }
+
+        for event in events {
+            self.track.notify(|| event);
+        }
+
+        Ok(insertions)
+    }
+}
+
+impl<I: TrackInterpolator> Track<I> {
+    pub fn transaction(&mut self) -> Transaction<'_, I> {
+        Transaction {
+            track: self,
+            mutations: vec![]
+        }
+    }
+}
+
+/// Track fields which can be changed by the mutations
+pub(crate) struct TrackState<I: TrackInterpolator> {
+    ranges: Buffer<TrackRange>,
+    buf: Buffer<Node<I>>,
+    buf_index_base: usize,
+    aligned_step: KeyDistance<I>,
+    schedule: StepSchedule<Key<I>>,
+    next_step: KeyDistance<I>,
+    key_start: Key<I>,
+    key_end: Key<I>,
+    not_aligned_indices: VecDeque<usize>,
+    journal_len: Option<usize>,
+    version: usize,
+}
+
+impl<I: TrackInterpolator> TrackState<I> {
+    pub(crate) fn save(track: &Track<I>) -> Self {
+        Self {
+            ranges: track.ranges.clone(),
+            buf: track.buf.clone(),
+            buf_index_base: track.buf_index_base,
+            aligned_step: track.aligned_step.clone(),
+            schedule: track.schedule.clone(),
+            next_step: track.next_step.clone(),
+            key_start: track.key_start.clone(),
+            key_end: track.key_end.clone(),
+            not_aligned_indices: track.not_aligned_indices.clone(),
+            journal_len: track.journal.as_ref().map(Vec::len),
+            version: track.version,
+        }
+    }
+
+    /// Observers are not notified, since the track is the same as before
+    pub(crate) fn restore(self, track: &mut Track<I>) {
+        track.ranges = self.ranges;
+        track.buf = self.buf;
+        track.buf_index_base = self.buf_index_base;
+        track.aligned_step = self.aligned_step;
+        track.schedule = self.schedule;
+        track.next_step = self.next_step;
+        track.key_start = self.key_start;
+        track.key_end = self.key_end;
+        track.not_aligned_indices = self.not_aligned_indices;
+        track.version = self.version;
+        track.reindex_aggregates();
+
+        if let (Some(journal), Some(len)) = (track.journal.as_mut(), self.journal_len) {
+            journal.truncate(len);
+        }
+    }
+}
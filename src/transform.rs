@@ -0,0 +1,112 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    ScalableDistance,
+    TrackNode,
+    TrackMutation,
+    Error,
+    Result,
+    KeyDistance,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Moves every key of the track by the `distance`.
+    /// Only the not aligned nodes are visited, the aligned keys are moved with the schedule.
+    pub fn shift(&mut self, distance: &KeyDistance<I>) {
+        self.record(|| TrackMutation::Shift(distance.clone()));
+
+        let old_key_start = self.key_start.clone();
+        let old_key_end = self.key_end.clone();
+
+        self.key_start = self.key_start.add_distance(distance);
+        self.key_end = self.key_end.add_distance(distance);
+        self.schedule.shift(distance);
+
+        for index in self.not_aligned_indices.iter() {
+            let node = match index.checked_sub(self.buf_index_base) {
+                Some(index) if index < self.buf.len() => &mut self.buf[index],
+                _ => continue
+            };
+
+            if let TrackNode::NotAligned(ref mut node) = node {
+                node.key = node.key.add_distance(distance);
+                node.canceled_key = node.canceled_key.add_distance(distance);
+            }
+        }
+
+        self.notify_replaced(old_key_start, old_key_end);
+    }
+}
+
+impl<I> Track<I>
+where
+    I: TrackInterpolator,
+    KeyDistance<I>: ScalableDistance
+{
+    /// Scales distances between the track start and every key by the `factor`.
+    /// The aligned step is scaled as well.
+    /// Aligned keys are derived from the scaled steps, not aligned keys are scaled within their ranges.
+    /// Returns `Error::ScaleCollapsesKeys` and keeps the track if some keys become equal.
+    pub fn scale_time(&mut self, factor: f64) -> Result<()> {
+        assert!(factor > 0.0);
+
+        let aligned_step = self.aligned_step.scale_f64(factor);
+        let mut schedule = self.schedule.clone();
+        if !schedule.scale(factor) || aligned_step <= KeyDistance::<I>::default() {
+            return Err(Error::ScaleCollapsesKeys);
+        }
+
+        let mut not_aligned_keys = vec![];
+        let mut prev_key = None;
+        for index in 0..self.buf.len() {
+            let node = match self.buf[index] {
+                TrackNode::NotAligned(ref node) => node,
+                TrackNode::Aligned(_) => continue
+            };
+
+            let key = node.key.clone();
+            let canceled_key = node.canceled_key.clone();
+            let range_index = self.range_index(&key);
+            let old_range_key = self.schedule.range_key(range_index);
+            let range_key = schedule.range_key(range_index);
+            let scale_key = |key| range_key.add_distance(
+                &old_range_key.distance(key).scale_f64(factor)
+            );
+
+            let key = scale_key(&key);
+            // `Option::is_none_or` needs a newer Rust
+            #[allow(clippy::unnecessary_map_or)]
+            let is_after_prev = prev_key.map_or(true, |prev_key| prev_key < key);
+            if !is_after_prev || key <= range_key || key >= schedule.range_key(range_index + 1) {
+                return Err(Error::ScaleCollapsesKeys);
+            }
+
+            prev_key = Some(key.clone());
+            not_aligned_keys.push((index, key, scale_key(&canceled_key)));
+        }
+
+        let old_key_end = self.key_end.clone();
+
+        self.schedule = schedule;
+        self.aligned_step = aligned_step;
+        for (index, key, canceled_key) in not_aligned_keys {
+            if let TrackNode::NotAligned(ref mut node) = self.buf[index] {
+                node.key = key;
+                node.canceled_key = canceled_key;
+            }
+        }
+
+        if self.buf.is_empty() {
+            self.key_end = self.key_start.clone();
+            self.next_step = self.schedule.step(0).clone();
+        } else {
+            self.set_end_by_node(self.buf.len() - 1);
+        }
+
+        self.record(|| TrackMutation::ScaleTime(factor));
+        self.notify_replaced(self.key_start.clone(), old_key_end);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{
+    Track,
+    TrackInterpolator,
+    TrackNode,
+    TrackNodeMut,
+    TrackEvent,
+    TrackMutation,
+    Key,
+    Data,
+    NotAlignedData,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Changes the data of the node with the `key` in place, nothing is canceled.
+    /// The `canceled_node` of a not aligned node stays as it was predicted.
+    /// Returns `None` if there is no node with the `key`.
+    pub fn update<F, R>(&mut self, key: &Key<I>, f: F) -> Option<R>
+    where
+        F: FnOnce(TrackNodeMut<'_, Data<I>, NotAlignedData<I>>) -> R
+    {
+        let cursor = self.cursor_at(key)
+            .filter(|cursor| self.cursor_key(*cursor) == *key)?;
+
+        let result = match self.buf[cursor.index - self.buf_index_base] {
+            TrackNode::Aligned(ref mut data) => f(TrackNodeMut::Aligned(data)),
+            TrackNode::NotAligned(ref mut node) => f(TrackNodeMut::NotAligned(&mut node.node)),
+        };
+
+        self.index_node(cursor.index);
+
+        let mutation = self.journal_entry(|| match self.buf[cursor.index - self.buf_index_base] {
+            TrackNode::Aligned(ref data) => TrackMutation::Update(key.clone(), data.clone()),
+            TrackNode::NotAligned(ref node) => TrackMutation::UpdateNotAligned(key.clone(), node.node.clone()),
+        });
+        self.record_entry(mutation);
+
+        let begin = self.cursor_prev(cursor).map_or_else(|| key.clone(), |prev| self.cursor_key(prev));
+        let end = self.cursor_next(cursor).map_or_else(|| key.clone(), |next| self.cursor_key(next));
+        self.notify(|| TrackEvent::Updated {
+            begin,
+            end,
+            key: key.clone()
+        });
+
+        Some(result)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    iter::NodeCursor,
+    interpolator::TrackWindow,
+    Track,
+    TrackInterpolator,
+    TrackKey,
+    Key,
+    KeyDistance,
+    Data,
+    NotAlignedData,
+    Output,
+    Error,
+    InterpolationResult,
+};
+
+impl<I: TrackInterpolator> Track<I> {
+    /// Interpolates between two nodes, passing the window around them if the interpolator needs it.
+    /// Keys of the `rhs` node and the nodes after it are moved by the `rhs_shift`.
+    pub(crate) fn interpolate_between(
+        &mut self,
+        key: &Key<I>,
+        lhs: NodeCursor,
+        rhs: NodeCursor,
+        rhs_shift: Option<&KeyDistance<I>>
+    ) -> InterpolationResult<Output<I>, I::Error> {
+        let shift = |key: Key<I>| match rhs_shift {
+            Some(distance) => key.add_distance(distance),
+            None => key
+        };
+
+        let base = self.buf_index_base;
+
+        if I::WINDOW == 0 {
+            let lhs_key = self.cursor_key(lhs);
+            let rhs_key = shift(self.cursor_key(rhs));
+
+            return self.interpolator.interpolate(
+                key,
+                lhs_key,
+                &self.buf[lhs.index - base],
+                rhs_key,
+                &self.buf[rhs.index - base]
+            ).map_err(Error::Interpolation);
+        }
+
+        self.with_window(lhs, rhs, rhs_shift, |interpolator, window| {
+            interpolator.interpolate_window(key, window)
+        }).map_err(Error::Interpolation)
+    }
+
+    /// Calls the `f` with the window around the nodes.
+    /// Keys of the `rhs` node and the nodes after it are moved by the `rhs_shift`.
+    /// The window of a looping track wraps around the track edges, the wrapped keys are moved by the loop length.
+    pub(crate) fn with_window<F, R>(
+        &mut self,
+        lhs: NodeCursor,
+        rhs: NodeCursor,
+        rhs_shift: Option<&KeyDistance<I>>,
+        f: F
+    ) -> R
+    where
+        F: FnOnce(&mut I, &TrackWindow<'_, Key<I>, Data<I>, NotAlignedData<I>>) -> R
+    {
+        let loop_length = self.loop_length.clone();
+        let mut positions = Vec::with_capacity(2 * I::WINDOW + 2);
+
+        let mut shift = KeyDistance::<I>::default();
+        let mut cursor = Some(lhs);
+        while positions.len() <= I::WINDOW {
+            let current = match (cursor, loop_length.as_ref()) {
+                (Some(current), _) => current,
+                (None, Some(loop_length)) => {
+                    shift = shift - loop_length.clone();
+                    self.cursor_last().unwrap()
+                },
+                (None, None) => break
+            };
+
+            positions.push((self.cursor_key(current).add_distance(&shift), current.index));
+            cursor = self.cursor_prev(current);
+        }
+
+        positions.reverse();
+        let lhs_index = positions.len() - 1;
+
+        let mut shift = rhs_shift.cloned().unwrap_or_default();
+        let mut cursor = Some(rhs);
+        while positions.len() <= lhs_index + I::WINDOW + 1 {
+            let current = match (cursor, loop_length.as_ref()) {
+                (Some(current), _) => current,
+                (None, Some(loop_length)) => {
+                    shift = shift + loop_length.clone();
+                    self.cursor_first().unwrap()
+                },
+                (None, None) => break
+            };
+
+            positions.push((self.cursor_key(current).add_distance(&shift), current.index));
+            cursor = self.cursor_next(current);
+        }
+
+        let base = self.buf_index_base;
+        let buf = &self.buf;
+        let nodes = positions
+            .into_iter()
+            .map(|(key, index)| (key, &buf[index - base]))
+            .collect();
+
+        let window = TrackWindow::new(nodes, lhs_index);
+
+        f(&mut self.interpolator, &window)
+    }
+}